@@ -232,6 +232,10 @@ fn handle_withdraw_message(
                 .to_fixed_bytes()
                 .map(|x| primitives::H256::from_slice(&x))
                 .expect("can not parse message_id");
+            let eth_unlock_tx = log
+                .transaction_hash
+                .map(|x| primitives::H256::from_slice(&x.0))
+                .expect("can not get unlock tx hash");
 
             let sub_validator_mnemonic_phrase = config.sub_validator_mnemonic_phrase.clone();
             let sub_api = sub_api.clone();
@@ -242,8 +246,13 @@ fn handle_withdraw_message(
                             &sub_api,
                             sub_validator_mnemonic_phrase.clone(),
                             message_id,
+                            eth_unlock_tx,
+                        );
+                        log::info!(
+                            "[substrate] called confirm_transfer({:?}, {:?})",
+                            message_id,
+                            eth_unlock_tx
                         );
-                        log::info!("[substrate] called confirm_transfer({:?})", message_id);
                     })
                     .map_err(|_| panic!("the threadpool shut down"))
                 })
@@ -113,10 +113,83 @@ fn start_event_handler(
                                             _from,
                                             _to,
                                             _amount,
+                                            _eth_unlock_tx,
                                         ) => (),
                                         bridge::RawEvent::Minted(message_id) => {
                                             handle_minted(&web3, &abi, &config, message_id)
                                         }
+                                        bridge::RawEvent::Signed(
+                                            _transfer_id,
+                                            _validator,
+                                            _votes,
+                                        ) => (),
+                                        bridge::RawEvent::TransferForceClosed(_message_id) => (),
+                                        bridge::RawEvent::ValidatorSetReplaced(_validators) => (),
+                                        bridge::RawEvent::MintFailed(message_id) => log::warn!(
+                                            "[substrate] mint {:?} failed and waits for retry",
+                                            message_id
+                                        ),
+                                        bridge::RawEvent::ValidatorSetLow(count) => log::warn!(
+                                            "[substrate] validator set is low: {} validators left",
+                                            count
+                                        ),
+                                        bridge::RawEvent::ShadowSigned(
+                                            _transfer_id,
+                                            _validator,
+                                        ) => (),
+                                        bridge::RawEvent::FraudBountyPaid(
+                                            _message_id,
+                                            _reporter,
+                                            _bounty,
+                                        ) => (),
+                                        // this validator relays to ethereum only
+                                        bridge::RawEvent::ChainRelayMessage(
+                                            _message_id,
+                                            _chain_id,
+                                        ) => (),
+                                        bridge::RawEvent::ApprovedChainRelayMessage(
+                                            _message_id,
+                                            _from,
+                                            _chain_id,
+                                            _to,
+                                            _amount,
+                                        ) => (),
+                                        bridge::RawEvent::MintDeferred(_message_id) => (),
+                                        bridge::RawEvent::MintPermanentlyFailed(message_id) => {
+                                            log::warn!(
+                                                "[substrate] mint {:?} failed too many times and was cancelled",
+                                                message_id
+                                            )
+                                        }
+                                        bridge::RawEvent::TransferIndexed(
+                                            _transfer_id,
+                                            _message_id,
+                                            _sequence,
+                                            _amount,
+                                        ) => (),
+                                        bridge::RawEvent::LockedBalanceCorrected(
+                                            account,
+                                            old_locked,
+                                            new_locked,
+                                        ) => log::warn!(
+                                            "[substrate] locked balance of {:?} corrected from {} to {}",
+                                            account,
+                                            old_locked,
+                                            new_locked
+                                        ),
+                                        bridge::RawEvent::ProposalExpired(_transfer_id) => (),
+                                        bridge::RawEvent::MintedBalance(
+                                            _message_id,
+                                            _account,
+                                            _before,
+                                            _after,
+                                        ) => (),
+                                        bridge::RawEvent::BurnedBalance(
+                                            _message_id,
+                                            _account,
+                                            _before,
+                                            _after,
+                                        ) => (),
                                     }
                                 }
                                 _ => log::debug!(
@@ -48,11 +48,13 @@ pub fn confirm_transfer(
     sub_api: &Api,
     signer_mnemonic_phrase: String,
     message_id: primitives::H256,
+    eth_unlock_tx: primitives::H256,
 ) {
     let xthex = build_confirm_transfer(
         &sub_api,
         get_sr25519_pair(&signer_mnemonic_phrase),
         message_id,
+        eth_unlock_tx,
     );
     //send and watch extrinsic until finalized
     let _tx_hash = sub_api.send_extrinsic(xthex);
@@ -117,10 +119,15 @@ pub fn build_approve_transfer(sub_api: &Api, signer: sr25519::Pair, message_id:
     xthex
 }
 
-pub fn build_confirm_transfer(sub_api: &Api, signer: sr25519::Pair, message_id: H256) -> String {
+pub fn build_confirm_transfer(
+    sub_api: &Api,
+    signer: sr25519::Pair,
+    message_id: H256,
+    eth_unlock_tx: H256,
+) -> String {
     let signer_index = signer_index(sub_api, &signer);
     let genesis_hash = sub_api.genesis_hash.expect("can not get genesiss hash");
-    let function = Call::Bridge(BridgeCall::confirm_transfer(message_id));
+    let function = Call::Bridge(BridgeCall::confirm_transfer(message_id, eth_unlock_tx));
     let era = Era::immortal();
 
     log::debug!("using genesis hash: {:?}", genesis_hash);
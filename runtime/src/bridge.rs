@@ -4,18 +4,37 @@
 ///
 use crate::token;
 use crate::types::{
-    BridgeMessage, BridgeTransfer, Kind, MemberId, ProposalId, Status, TokenBalance,
-    TransferMessage, ValidatorMessage,
+    AccountPosition, BridgeMessage, BridgeTransfer, CancelMessage, Direction, EffectiveParams,
+    EthSignature, EventSummary, Kind, LimitMessage, MemberId, ProposalId, QuorumConfig, Status,
+    TokenBalance, TransferMessage, ValidatorMessage, WithdrawPhase,
 };
 use parity_codec::Encode;
-use primitives::H160;
-use runtime_primitives::traits::{As, Hash};
+use primitives::{H160, H256};
+use rstd::prelude::Vec;
+use runtime_primitives::traits::{As, CheckedAdd, Hash, Saturating, Zero};
 use support::{
-    decl_event, decl_module, decl_storage, dispatch::Result, ensure, StorageMap, StorageValue,
+    decl_event, decl_module, decl_storage, dispatch::Result, ensure, traits::ReservableCurrency,
+    StorageMap, StorageValue,
 };
-use system::{self, ensure_signed};
+use system::{self, ensure_root, ensure_signed};
 
 const MAX_VALIDATORS: u32 = 100_000;
+const MIN_VALIDATORS: u32 = 1;
+// share of total validator weight needed to execute a proposal
+const QUORUM_PERCENT: u32 = 51;
+const MAX_TRANSFERS_PAGE: u32 = 100;
+const MAX_BATCH_SIZE: usize = 100;
+const MAX_METADATA_LEN: usize = 256;
+const MAX_ADDRESS_LEN: usize = 64;
+const MAX_AMOUNT_TIERS: usize = 8;
+// chain id of set_transfer destinations
+const ETHEREUM_CHAIN_ID: u32 = 1;
+const DAY_SECONDS: u64 = 86_400;
+const FEE_RETENTION_DAYS: u64 = 365;
+const MAX_RECENT_EVENTS: usize = 1000;
+// proposals participation_rate can look back over
+const MAX_PARTICIPATION_WINDOW: u64 = 1000;
+const MAX_REEVALUATIONS_PER_BLOCK: u64 = 100;
 
 decl_event!(
     pub enum Event<T>
@@ -26,17 +45,130 @@ decl_event!(
         RelayMessage(Hash),
         ApprovedRelayMessage(Hash, AccountId, H160, TokenBalance),
         Minted(Hash),
-        Burned(Hash, AccountId, H160, TokenBalance),
+        Burned(Hash, AccountId, H160, TokenBalance, H256),
+        Signed(ProposalId, AccountId, MemberId),
+        TransferForceClosed(Hash),
+        ValidatorSetReplaced(Vec<AccountId>),
+        ValidatorSetLow(u32),
+        MintFailed(Hash),
+        ShadowSigned(ProposalId, AccountId),
+        FraudBountyPaid(Hash, AccountId, TokenBalance),
+        ChainRelayMessage(Hash, u32),
+        ApprovedChainRelayMessage(Hash, AccountId, u32, Vec<u8>, TokenBalance),
+        MintDeferred(Hash),
+        // proposal id, message id, sequence number and amount, only with indexing hints on
+        TransferIndexed(ProposalId, Hash, u64, TokenBalance),
+        MintPermanentlyFailed(Hash),
+        // account, locked amount before and after the correction
+        LockedBalanceCorrected(AccountId, TokenBalance, TokenBalance),
+        ProposalExpired(ProposalId),
+        // message id, account, token balance before and after the operation
+        MintedBalance(Hash, AccountId, TokenBalance, TokenBalance),
+        BurnedBalance(Hash, AccountId, TokenBalance, TokenBalance),
     }
 );
 
-pub trait Trait: token::Trait + system::Trait {
+/// called after the bridge minted tokens for an ethereum deposit
+pub trait OnMintHandler<AccountId, Hash> {
+    fn on_mint(message_id: &Hash, to: &AccountId, amount: TokenBalance);
+}
+
+impl<AccountId, Hash> OnMintHandler<AccountId, Hash> for () {
+    fn on_mint(_: &Hash, _: &AccountId, _: TokenBalance) {}
+}
+
+/// called after the bridge burned tokens of a confirmed withdraw
+pub trait OnBurnHandler<AccountId, Hash> {
+    fn on_burn(message_id: &Hash, from: &AccountId, to: H160, amount: TokenBalance);
+}
+
+impl<AccountId, Hash> OnBurnHandler<AccountId, Hash> for () {
+    fn on_burn(_: &Hash, _: &AccountId, _: H160, _: TokenBalance) {}
+}
+
+/// substrate side condition an approved mint has to meet, otherwise it's deferred
+pub trait MintCondition<AccountId, Hash> {
+    fn can_mint(message_id: &Hash, to: &AccountId, amount: TokenBalance) -> bool;
+}
+
+impl<AccountId, Hash> MintCondition<AccountId, Hash> for () {
+    fn can_mint(_: &Hash, _: &AccountId, _: TokenBalance) -> bool {
+        true
+    }
+}
+
+pub trait Trait: token::Trait + system::Trait + timestamp::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+    type OnMint: OnMintHandler<Self::AccountId, Self::Hash>;
+    type OnBurn: OnBurnHandler<Self::AccountId, Self::Hash>;
+    type MintCondition: MintCondition<Self::AccountId, Self::Hash>;
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Bridge {
+        FeeAccount get(fee_account): T::AccountId;
+        // direction specific fee recipients, fee_account is used when not set
+        MintFeeAccount get(mint_fee_account): Option<T::AccountId>;
+        BurnFeeAccount get(burn_fee_account): Option<T::AccountId>;
+        // flat fee charged on ethereum -> substrate transfers
+        MintFee get(mint_fee): TokenBalance;
+        // flat fee charged on substrate -> ethereum transfers
+        WithdrawFee get(withdraw_fee): TokenBalance;
+        // smallest deposit or withdraw, in base units
+        MinTransferAmount get(min_transfer_amount): TokenBalance;
+        // mints can't push total supply above it
+        MaxTotalSupply get(max_total_supply): TokenBalance = TokenBalance::max_value();
+        // gross amount of approved withdraws waiting for burn
+        PendingBurnTotal get(pending_burn_total): TokenBalance;
+        // collateral locked on ethereum side as last reported by the operator
+        ReportedCollateral get(reported_collateral): TokenBalance;
+        // percent of reported collateral the supply must stay at after a withdraw, 0 is off
+        MinReserveRatio get(min_reserve_ratio): u32;
+        // fee part of the funds locked for an approved withdraw
+        LockedFees get(locked_fee): map(T::Hash) => TokenBalance;
+        // fees collected per day index, kept for FEE_RETENTION_DAYS
+        DailyFees get(daily_fees): map u64 => TokenBalance;
+        // days with a DailyFees entry, oldest first
+        FeeDays get(fee_days): Vec<u64>;
+        // ethereum tx which unlocked funds for a withdraw
+        EthUnlockTx get(eth_unlock_tx): map(T::Hash) => H256;
+
+        // permissioned deployments allow only whitelisted accounts to withdraw
+        WithdrawWhitelistEnabled get(withdraw_whitelist_enabled): bool = false;
+        WithdrawWhitelist get(withdraw_whitelist): map(T::AccountId) => bool;
+        // bridge controlled ethereum addresses which can't receive withdraws
+        ReservedEthAddresses get(reserved_eth_address): map(H160) => bool;
+        // lock events older than this many seconds can't be minted, 0 disables the check
+        MaxMintAge get(max_mint_age): u64 = 0;
+        // ethereum timestamp of the lock, agreed by all mint signers
+        MintLockTimestamp get(mint_lock_timestamp): map(T::Hash) => u64;
+        // ethereum keys trusted to sign lock events
+        EthSigners get(eth_signer): map(H160) => bool;
+        // mint votes have to go through multi_signed_mint_with_proof
+        MintProofRequired get(mint_proof_required): bool;
+        // distinct ethereum senders of executed mints, recorded only when enabled
+        TrackEthSenders get(track_eth_senders): bool = false;
+        KnownEthSenders get(known_eth_senders): Vec<H160>;
+        // ethereum addresses an account received mints from
+        MintSources get(mint_sources): map(T::AccountId) => Vec<H160>;
+        // accounts mints can't go to, the default (zero) account is always refused
+        ReservedMintRecipients get(reserved_mint_recipients): Vec<T::AccountId>;
+        // refunds of cancelled withdraws stay locked until claimed, off by default
+        ClaimableRefundsEnabled get(claimable_refunds_enabled): bool = false;
+        ClaimableRefunds get(claimable_refund): map(T::AccountId) => TokenBalance;
+        // minted funds stay locked until the recipient calls claim_minted, off by default
+        MintEscrowEnabled get(mint_escrow_enabled): bool = false;
+        EscrowedMints get(escrowed_mint): map(T::Hash, T::AccountId) => TokenBalance;
+
         BridgeIsOperational get(bridge_is_operational): bool = true;
+        Bootstrapping get(bootstrapping): bool = true;
+        // validators can't mint to themselves while bootstrapping
+        NoValidatorSelfMint get(no_validator_self_mint): bool = false;
+        // incremented on every resume, so each pause/resume cycle gets its own proposals
+        PauseEpoch get(pause_epoch): u64;
+        // single direction pauses on top of the bridge-wide one
+        DepositsPaused get(deposits_paused): bool = false;
+        WithdrawsPaused get(withdraws_paused): bool = false;
         BridgeMessages get(bridge_messages): map (T::Hash) => BridgeMessage<T::AccountId, T::Hash>;
 
         BridgeTransfers get(transfers): map ProposalId => BridgeTransfer<T::Hash>;
@@ -44,14 +176,143 @@ decl_storage! {
         TransferMessages get(messages): map(T::Hash) => TransferMessage<T::AccountId, T::Hash>;
         TransferId get(transfer_id_by_hash): map(T::Hash) => ProposalId;
         MessageId get(message_id_by_transfer_id): map(ProposalId) => T::Hash;
+        // burn confirmation proposal of a withdraw, transfer_id_by_hash keeps the approval one
+        ConfirmationId get(confirmation_id_by_hash): map(T::Hash) => Option<ProposalId>;
+        TransferCreatedAt get(transfer_created_at): map(ProposalId) => T::BlockNumber;
+        // transfer message ids grouped by their current status, see transfers_with_status
+        TransfersByStatus get(transfer_by_status): map(Status, u64) => T::Hash;
+        TransfersByStatusCount get(transfers_with_status_count): map(Status) => u64;
+        // position of the message id in the bucket of its status
+        TransferStatusPosition get(transfer_status_position): map(T::Hash) => Option<u64>;
+        // withdraws of the account which aren't burned or cancelled yet, see account_position
+        OpenWithdraws get(open_withdraws): map(T::AccountId) => Vec<T::Hash>;
+        // rejected calls and failed executions, for monitoring
+        RejectedWithdrawsCount get(rejected_withdraws_count): u64;
+        RejectedMintsCount get(rejected_mints_count): u64;
+        FailedExecutionsCount get(failed_executions_count): u64;
+        // validators which voted in the current phase of a proposal, bit per ValidatorIndex
+        SignatureBits get(signature_bits): map ProposalId => Vec<u8>;
+        // approved deposits whose mint failed, see retry_mint
+        FailedMints get(failed_mints): Vec<T::Hash>;
+        // verbose TransferIndexed events for off-chain indexers, off to save weight
+        IndexingHints get(indexing_hints): bool = false;
+        IndexedEventsCount get(indexed_events_count): u64;
+        // withdraw requests made by account, part of the transfer hash
+        WithdrawNonce get(withdraw_nonce): map T::AccountId => u64;
+        // approved deposits waiting for MintCondition, see retry_mint
+        DeferredMints get(deferred_mints): Vec<T::Hash>;
+        // failed retry_mint calls per mint, it's cancelled at MaxMintRetries
+        RetryCount get(retry_count): map T::Hash => u32;
+        MaxMintRetries get(max_mint_retries): u32 = 10;
+        // ids of proposals that are still open, see open_proposals
+        OpenProposals get(open_proposal_at): map u64 => ProposalId;
+        OpenProposalsCount get(open_proposals_count): u64;
+        OpenProposalPosition get(open_proposal_position): map ProposalId => Option<u64>;
+        CancelMessages get(cancel_messages): map(T::Hash) => CancelMessage<T::AccountId, T::Hash>;
+        // chain id and address of withdraws to chains other than ethereum
+        TransferDestinations get(transfer_destination): map(T::Hash) => Option<(u32, Vec<u8>)>;
+        // deposits announced by a validator before the lock event: (validator, to, amount)
+        ExpectedMints get(expected_mint): map(T::Hash) => Option<(T::AccountId, T::AccountId, TokenBalance)>;
+        // recipients of a deposit split between several accounts
+        MintRecipients get(mint_recipients): map(T::Hash) => Vec<(T::AccountId, TokenBalance)>;
+        // first reporter of a transfer and the evidence, paid if the transfer gets cancelled
+        FraudReports get(fraud_report): map(T::Hash) => Option<(T::AccountId, Vec<u8>)>;
+        // paid from fee_account, at most its balance
+        FraudBounty get(fraud_bounty): TokenBalance;
+        // message ids of pruned transfers, they can't be submitted again
+        ProcessedMessages get(processed_message): map(T::Hash) => bool;
 
 
-        ValidatorsCount get(validators_count) config(): u32 = 3;
+        // derived from the genesis accounts so quorum math can't start out of sync
+        ValidatorsCount get(validators_count) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.len() as u32
+        }): u32 = 3;
+        // never decreases, genesis validators included
+        TotalValidatorsEverAdded get(total_validators_ever_added) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.len() as u64
+        }): u64;
+        // effective validators maximum, can't exceed MAX_VALIDATORS
+        MaxValidators get(max_validators): u32 = MAX_VALIDATORS;
+        // ValidatorSetLow is emitted when removals leave at most MIN_VALIDATORS + margin
+        ValidatorSetLowMargin get(validator_set_low_margin): u32 = 1;
+        // (amount, extra votes): transfers above amount need extra votes on top of the quorum
+        AmountTiers get(amount_tiers): Vec<(TokenBalance, MemberId)>;
+        // distinct signatures required on top of the percentage threshold
+        MinAbsoluteVotes get(min_absolute_votes): MemberId = 1;
+        LimitMessages get(limit_messages): map (T::Hash) => LimitMessage<T::AccountId, T::Hash>;
         ValidatorHistory get(validator_history): map (T::Hash) => ValidatorMessage<T::AccountId, T::Hash>;
+        // open validator proposals older than this are dropped and can be proposed again
+        ValidatorProposalTtl get(validator_proposal_ttl): T::BlockNumber = T::BlockNumber::sa(8640);
+        // blocks a new proposal of any kind gets to reach quorum, 0 means no deadline
+        ProposalTtl get(proposal_ttl): T::BlockNumber;
+        ProposalDeadline get(proposal_deadline): map ProposalId => Option<T::BlockNumber>;
+        // (deadline, proposal id) ordered by deadline, swept in on_finalize
+        DeadlineQueue get(deadline_queue): Vec<(T::BlockNumber, ProposalId)>;
+        // transfer transitions of the last RecentEventsRetention blocks, see recent_events
+        RecentEvents: Vec<(T::BlockNumber, EventSummary<T::Hash>)>;
+        RecentEventsRetention get(recent_events_retention): T::BlockNumber = T::BlockNumber::sa(600);
+        // open proposal positions reconcile_all has left to check, 0 starts a new pass
+        ReconcileCursor get(reconcile_cursor): u64;
+        // proposals below it are pruned or never will be, prune_finalized starts here
+        PruneCursor get(prune_cursor): ProposalId;
+        // transfer the next prune_finalized looks at first, 0 restarts from PruneCursor
+        PruneScanCursor get(prune_scan_cursor): ProposalId;
+        // open proposal positions left to re-check after the set shrank, from the top down
+        ReevaluationCursor get(reevaluation_cursor): u64;
+        // blocks between quorum on an addition and activation, validators can veto meanwhile
+        ValidatorAddDelay get(validator_add_delay): T::BlockNumber;
+        // accounts waiting for activation with the block they become active at
+        PendingValidatorAdditions get(pending_validator_additions): Vec<(T::AccountId, T::BlockNumber)>;
         Validators get(validators) build(|config: &GenesisConfig<T>| {
             config.validator_accounts.clone().into_iter()
             .map(|acc: T::AccountId| (acc, true)).collect::<Vec<_>>()
         }): map (T::AccountId) => bool;
+        ValidatorWeight get(validator_weight) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.clone().into_iter()
+            .map(|acc: T::AccountId| (acc, 1)).collect::<Vec<_>>()
+        }): map (T::AccountId) => u32;
+        TotalValidatorWeight get(total_validator_weight) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.len() as u32
+        }): u32;
+        // validators whose votes are only recorded, their weight isn't in the total
+        ShadowValidators get(is_shadow_validator): map (T::AccountId) => bool;
+        ShadowVotes get(shadow_votes): map ProposalId => Vec<T::AccountId>;
+        // position of validator's bit in SignatureBits, never reused so old bitmaps stay exact
+        ValidatorIndex get(validator_index) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.clone().into_iter().enumerate()
+            .map(|(index, acc): (usize, T::AccountId)| (acc, index as u32)).collect::<Vec<_>>()
+        }): map (T::AccountId) => u32;
+        NextValidatorIndex get(next_validator_index) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.len() as u32
+        }): u32;
+        // owner of each ValidatorIndex, kept after the validator is removed
+        IndexedValidators get(indexed_validator) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.clone().into_iter().enumerate()
+            .map(|(index, acc): (usize, T::AccountId)| (index as u32, acc)).collect::<Vec<_>>()
+        }): map u32 => T::AccountId;
+        ValidatorAccounts get(validator_accounts) build(|config: &GenesisConfig<T>| {
+            config.validator_accounts.clone()
+        }): Vec<T::AccountId>;
+        // last resort governance above validators, see super_replace_validators
+        SuperAdmins get(super_admins) config(): Vec<T::AccountId>;
+        SuperAdminVotes get(super_admin_votes): map(T::Hash) => Vec<T::AccountId>;
+        SuperAdminEpoch get(super_admin_epoch): u64;
+        // validators allowed to sign mints and pauses, empty means all of them;
+        // a configured set decides on its own by the quorum of its weight
+        MintValidators get(mint_validators): Vec<T::AccountId>;
+        PauseValidators get(pause_validators): Vec<T::AccountId>;
+        // account for operational calls, rotated by itself without root
+        Operator get(operator) config(): T::AccountId;
+        // contact data, e.g. relayer api url, set by each validator for itself
+        ValidatorMetadata get(validator_metadata): map(T::AccountId) => Vec<u8>;
+        // last block in which validator's signature was counted
+        ValidatorLastActive get(validator_last_active): map (T::AccountId) => T::BlockNumber;
+        // reserved from an account when it becomes a validator, zero turns bonding off
+        ValidatorBond get(validator_bond): T::Balance;
+        // bond each validator posted, returned on voluntary exit and slashed on removal
+        BondedAmount get(bonded_amount): map (T::AccountId) => T::Balance;
+        // ids of the latest proposals signed by the validator, at most MAX_PARTICIPATION_WINDOW
+        SignedProposals get(signed_proposals): map (T::AccountId) => Vec<ProposalId>;
     }
     add_extra_genesis {
         config(validator_accounts): Vec<T::AccountId>;
@@ -62,26 +323,61 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event<T>() = default;
 
+        fn on_initialize(now: T::BlockNumber) {
+            Self::activate_pending_validators(now);
+            Self::continue_reevaluation();
+        }
+
+        fn on_finalize(now: T::BlockNumber) {
+            Self::expire_proposals(now);
+        }
+
         // initiate substrate -> ethereum transfer.
         // create proposition and emit the RelayMessage event
         fn set_transfer(origin, to: H160, #[compact] amount: TokenBalance)-> Result
         {
             let from = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::create_eth_withdraw(from, to, amount)
+        }
 
-            let transfer_hash = (&from, &to, amount, T::BlockNumber::sa(0)).using_encoded(<T as system::Trait>::Hashing::hash);
+        // initiate substrate -> chain_id transfer, ethereum ones go the set_transfer way
+        fn set_transfer_to_chain(origin, chain_id: u32, to: Vec<u8>, #[compact] amount: TokenBalance) -> Result {
+            let from = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            if chain_id == ETHEREUM_CHAIN_ID {
+                ensure!(to.len() == 20, "Invalid ethereum address");
+                return Self::create_eth_withdraw(from, H160::from_slice(&to), amount);
+            }
+            ensure!(
+                !to.is_empty() && to.len() <= MAX_ADDRESS_LEN,
+                "Invalid destination address"
+            );
+            if let Err(e) = Self::check_withdraw_amount(&from, amount) {
+                <RejectedWithdrawsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+                return Err(e);
+            }
 
+            let nonce = Self::withdraw_nonce(&from);
+            let next_nonce = nonce.checked_add(1).ok_or("Withdraw nonce overflow")?;
+            let transfer_hash = (&from, chain_id, &to, amount, nonce).using_encoded(<T as system::Trait>::Hashing::hash);
             let message = TransferMessage{
                 message_id: transfer_hash,
-                eth_address: to,
+                eth_address: H160::default(),
                 substrate_address: from,
                 amount,
                 status: Status::Withdraw,
                 action: Status::Withdraw,
+                phase: WithdrawPhase::Requested,
             };
             Self::get_transfer_id_checked(transfer_hash, Kind::Transfer)?;
-            Self::deposit_event(RawEvent::RelayMessage(transfer_hash));
+            <WithdrawNonce<T>>::insert(&message.substrate_address, next_nonce);
+            <TransferDestinations<T>>::insert(transfer_hash, (chain_id, to));
+            Self::deposit_event(RawEvent::ChainRelayMessage(transfer_hash, chain_id));
+            Self::emit_index_hint(transfer_hash, amount);
 
+            Self::reindex_transfer(transfer_hash, Some(Status::Withdraw));
+            <OpenWithdraws<T>>::mutate(&message.substrate_address, |v| v.push(transfer_hash));
             <TransferMessages<T>>::insert(transfer_hash, message);
             Ok(())
         }
@@ -90,25 +386,224 @@ decl_module! {
         fn multi_signed_mint(origin, message_id: T::Hash, from: H160, to: T::AccountId, #[compact] amount: TokenBalance)-> Result {
             let validator = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(!Self::mint_proof_required(), "Ethereum signature is required");
+            ensure!(Self::max_mint_age() == 0, "Lock timestamp is required");
 
-            Self::check_validator(validator)?;
+            Self::check_mint_validator(validator.clone())?;
+            Self::sign_mint(validator, message_id, from, to, amount)
+        }
 
-            if !<TransferMessages<T>>::exists(message_id) {
-                let message = TransferMessage{
-                    message_id,
-                    eth_address: from,
-                    substrate_address: to,
-                    amount,
-                    status: Status::Deposit,
-                    action: Status::Deposit,
+        // multi_signed_mint backed by a signature of a trusted ethereum signer
+        fn multi_signed_mint_with_proof(
+            origin,
+            message_id: T::Hash,
+            from: H160,
+            to: T::AccountId,
+            #[compact] amount: TokenBalance,
+            signature: EthSignature
+        ) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(Self::max_mint_age() == 0, "Lock timestamp is required");
+
+            Self::check_mint_validator(validator.clone())?;
+            let signer = Self::recover_eth_signer(message_id, from, &to, amount, &signature);
+            ensure!(
+                signer.map_or(false, |signer| Self::eth_signer(signer)),
+                "Invalid ethereum signature"
+            );
+            Self::sign_mint(validator, message_id, from, to, amount)
+        }
+
+        // announce an upcoming deposit, it counts as the validator's vote once it arrives
+        fn preregister_mint(origin, message_id: T::Hash, to: T::AccountId, #[compact] amount: TokenBalance) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            // the pre-registration counts as an unproven vote
+            ensure!(!Self::mint_proof_required(), "Ethereum signature is required");
+            Self::check_mint_validator(validator.clone())?;
+            ensure!(!<TransferMessages<T>>::exists(message_id), "Mint message already exists");
+            ensure!(!<ExpectedMints<T>>::exists(message_id), "Mint is already pre-registered");
+
+            <ExpectedMints<T>>::insert(message_id, (validator, to, amount));
+            Ok(())
+        }
+
+        // each validator calls it to drop a pre-registration which doesn't match the deposit
+        fn drop_preregistered_mint(origin, message_id: T::Hash) -> Result {
+            let validator = ensure_signed(origin)?;
+            Self::check_validator(validator.clone())?;
+            ensure!(<ExpectedMints<T>>::exists(message_id), "Mint is not pre-registered");
+            let hash = ("drop_preregistered_mint", message_id)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
+
+            if !<CancelMessages<T>>::exists(hash) {
+                let message = CancelMessage {
+                    message_id: hash,
+                    transfer_hash: message_id,
+                    account: validator.clone(),
+                    action: Status::DropPreregisteredMint,
+                    status: Status::DropPreregisteredMint,
                 };
-                <TransferMessages<T>>::insert(message_id, message);
-                Self::get_transfer_id_checked(message_id, Kind::Transfer)?;
+                <CancelMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Cancel)?;
+            }
+
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
+        }
+
+        // multi_signed_mint carrying the ethereum timestamp of the lock
+        fn multi_signed_mint_with_timestamp(
+            origin,
+            message_id: T::Hash,
+            from: H160,
+            to: T::AccountId,
+            #[compact] amount: TokenBalance,
+            lock_timestamp: u64
+        ) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(!Self::mint_proof_required(), "Ethereum signature is required");
+
+            Self::check_mint_validator(validator.clone())?;
+            Self::check_mint_age(message_id, lock_timestamp)?;
+            Self::sign_mint(validator, message_id, from, to, amount)?;
+            <MintLockTimestamp<T>>::insert(message_id, lock_timestamp);
+            Ok(())
+        }
+
+        // multi_signed_mint of one ethereum lock split between several recipients
+        fn multi_signed_mint_split(
+            origin,
+            message_id: T::Hash,
+            from: H160,
+            #[compact] total: TokenBalance,
+            recipients: Vec<(T::AccountId, TokenBalance)>
+        ) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(!Self::mint_proof_required(), "Ethereum signature is required");
+            ensure!(Self::max_mint_age() == 0, "Lock timestamp is required");
+
+            Self::check_mint_validator(validator.clone())?;
+            ensure!(!recipients.is_empty(), "Recipients list is empty");
+            ensure!(recipients.len() <= MAX_BATCH_SIZE, "Batch is too large");
+            let sum = recipients
+                .iter()
+                .try_fold(0, |sum: TokenBalance, (_, amount)| sum.checked_add(*amount));
+            ensure!(sum == Some(total), "Recipient amounts don't sum up to the total");
+            let checked = Self::check_mint(total).and_then(|_| {
+                recipients.iter().try_for_each(|(to, _)| Self::check_mint_recipient(to))
+            });
+            if let Err(e) = checked {
+                <RejectedMintsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+                return Err(e);
+            }
+
+            // every validator has to sign the same split
+            if <TransferMessages<T>>::exists(message_id) {
+                ensure!(Self::mint_recipients(message_id) == recipients, "Recipients mismatch");
+            } else {
+                Self::insert_mint_message(message_id, from, recipients[0].0.clone(), total)?;
+                <MintRecipients<T>>::insert(message_id, recipients);
             }
 
             let transfer_id = <TransferId<T>>::get(message_id);
-            Self::_sign(transfer_id)?;
+            Self::_sign(validator, transfer_id)
+        }
+
+        // multi_signed_mint for several messages, executed in ProposalId order
+        fn batch_multi_signed_mint(origin, mints: Vec<(T::Hash, H160, T::AccountId, TokenBalance)>) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(!Self::mint_proof_required(), "Ethereum signature is required");
+            ensure!(Self::max_mint_age() == 0, "Lock timestamp is required");
+
+            Self::check_mint_validator(validator.clone())?;
+            ensure!(mints.len() <= MAX_BATCH_SIZE, "Batch is too large");
+            let mut mints = mints;
+            // new messages get ids in message_id order, not in submission order
+            mints.sort_by(|a, b| a.0.as_ref().cmp(b.0.as_ref()));
+            let has_duplicates = mints.windows(2).any(|pair| pair[0].0 == pair[1].0);
+            ensure!(!has_duplicates, "Duplicate message in batch");
+            for (_, _, to, amount) in mints.iter() {
+                let checked =
+                    Self::check_mint(*amount).and_then(|_| Self::check_mint_recipient(to));
+                if let Err(e) = checked {
+                    <RejectedMintsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+                    return Err(e);
+                }
+            }
+            // storage isn't rolled back, so every vote is checked before the first one is cast
+            for (message_id, _, to, amount) in mints.iter() {
+                if !<TransferMessages<T>>::exists(message_id) {
+                    Self::check_expected_mint(*message_id, to, *amount)?;
+                }
+                Self::check_mint_vote(&validator, *message_id)?;
+            }
+
+            for (message_id, from, to, amount) in mints.iter() {
+                Self::insert_mint_message(*message_id, *from, to.clone(), *amount)?;
+            }
+            let mut transfer_ids: Vec<ProposalId> = mints
+                .iter()
+                .map(|(message_id, _, _, _)| <TransferId<T>>::get(message_id))
+                .collect();
+            transfer_ids.sort();
+            for transfer_id in transfer_ids {
+                Self::_sign(validator.clone(), transfer_id)?;
+            }
+
+            Ok(())
+        }
+
+        // anyone can retry a mint which failed or was deferred after reaching quorum
+        fn retry_mint(origin, message_id: T::Hash) -> Result {
+            let _ = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            ensure!(!Self::deposits_paused(), "Deposits are paused");
+            ensure!(
+                Self::failed_mints().contains(&message_id)
+                    || Self::deferred_mints().contains(&message_id),
+                "Mint is not queued for retry"
+            );
+            let message = <TransferMessages<T>>::get(message_id);
+            ensure!(message.status == Status::Approved, "Mint is not awaiting retry");
+            ensure!(Self::mint_condition_holds(&message), "Mint condition is not met");
+
+            if let Err(e) = Self::execute_mint(&message) {
+                let retries = Self::retry_count(message_id).saturating_add(1);
+                if retries >= Self::max_mint_retries() {
+                    return Self::abandon_mint(message_id);
+                }
+                <RetryCount<T>>::insert(message_id, retries);
+                return Err(e);
+            }
+            <FailedMints<T>>::mutate(|v| v.retain(|id| *id != message_id));
+            <DeferredMints<T>>::mutate(|v| v.retain(|id| *id != message_id));
+            <RetryCount<T>>::remove(message_id);
+            Ok(())
+        }
+
+        // validator publishes its own contact data
+        fn set_validator_metadata(origin, data: Vec<u8>) -> Result {
+            let validator = ensure_signed(origin)?;
+            Self::check_validator(validator.clone())?;
+            ensure!(data.len() <= MAX_METADATA_LEN, "Validator metadata is too long");
+
+            <ValidatorMetadata<T>>::insert(validator, data);
+            Ok(())
+        }
 
+        // anyone can report a transfer which is not finalized yet
+        fn report_fraud(origin, message_id: T::Hash, evidence: Vec<u8>) -> Result {
+            let reporter = ensure_signed(origin)?;
+            ensure!(evidence.len() <= MAX_METADATA_LEN, "Fraud evidence is too long");
+            Self::check_closable(message_id)?;
+            ensure!(!<FraudReports<T>>::exists(message_id), "Transfer is already reported");
+
+            <FraudReports<T>>::insert(message_id, (reporter, evidence));
             Ok(())
         }
 
@@ -116,20 +611,54 @@ decl_module! {
         fn approve_transfer(origin, message_id: T::Hash) -> Result {
             let validator = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
-            Self::check_validator(validator)?;
+            Self::check_validator(validator.clone())?;
+
+            ensure!(
+                <TransferMessages<T>>::exists(message_id),
+                "Transfer message does not exist"
+            );
+            let message = <TransferMessages<T>>::get(message_id);
+            ensure!(message.action == Status::Withdraw, "Use multi_signed_mint for deposits");
 
             let id = <TransferId<T>>::get(message_id);
-            Self::_sign(id)
+            Self::_sign(validator, id)
+        }
+
+        // each validator calls it to cancel a not yet executed mint
+        fn cancel_mint(origin, message_id: T::Hash) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::check_validator(validator.clone())?;
+
+            Self::check_mint_cancelable(message_id)?;
+            let hash = ("cancel_mint", message_id).using_encoded(<T as system::Trait>::Hashing::hash);
+
+            if !<CancelMessages<T>>::exists(hash) {
+                let message = CancelMessage {
+                    message_id: hash,
+                    transfer_hash: message_id,
+                    account: validator,
+                    action: Status::CancelMint,
+                    status: Status::CancelMint,
+                };
+                <CancelMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Cancel)?;
+            }
+
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
         }
 
         // each validator calls it to add new validator
         fn add_validator(origin, address: T::AccountId) -> Result {
             let validator = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
-            Self::check_validator(validator)?;
+            Self::check_validator(validator.clone())?;
+            ensure!(!<Validators<T>>::exists(&address), "Already a validator");
 
-            ensure!(<ValidatorsCount<T>>::get() < 100_000, "Validators maximum reached.");
+            ensure!(<ValidatorsCount<T>>::get() < Self::max_validators(), "Validators maximum reached.");
             let hash = ("add", &address).using_encoded(<T as system::Trait>::Hashing::hash);
+            Self::expire_validator_proposal(hash);
 
             if !<ValidatorHistory<T>>::exists(hash) {
                 let message = ValidatorMessage {
@@ -143,17 +672,62 @@ decl_module! {
             }
 
             let id = <TransferId<T>>::get(hash);
-            Self::_sign(id)
+            Self::_sign(validator, id)
+        }
+        // validator leaves the set by itself and gets its bond back
+        fn voluntary_exit(origin) -> Result {
+            let validator = ensure_signed(origin)?;
+            Self::check_validator(validator.clone())?;
+
+            let hash = ("remove", &validator).using_encoded(<T as system::Trait>::Hashing::hash);
+            let removal_voted = <TransferId<T>>::exists(hash)
+                && <BridgeTransfers<T>>::get(<TransferId<T>>::get(hash)).open;
+            ensure!(!removal_voted, "Validator removal is being voted");
+
+            Self::deactivate_validator(&validator)?;
+            Self::refund_validator_bond(&validator);
+            Ok(())
+        }
+
+        // each validator calls it to veto an addition which waits for activation
+        fn veto_validator(origin, address: T::AccountId) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::check_validator(validator.clone())?;
+
+            let activation = Self::pending_validator_additions()
+                .into_iter()
+                .find(|(account, _)| *account == address)
+                .map(|(_, at)| at)
+                .ok_or("Validator addition is not pending")?;
+            let hash = ("veto", &address, activation)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
+
+            if !<ValidatorHistory<T>>::exists(hash) {
+                let message = ValidatorMessage {
+                    message_id: hash,
+                    account: address,
+                    action: Status::VetoValidator,
+                    status: Status::VetoValidator,
+                };
+                <ValidatorHistory<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Validator)?;
+            }
+
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
         }
         // each validator calls it to remove new validator
         fn remove_validator(origin, address: T::AccountId) -> Result {
             let validator = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
-            Self::check_validator(validator)?;
+            Self::check_validator(validator.clone())?;
 
-            ensure!(<ValidatorsCount<T>>::get() > 1, "Can not remove last validator.");
+            ensure!(<ValidatorsCount<T>>::get() > MIN_VALIDATORS, "Can not remove last validator.");
+            ensure!(validator != address, "Validator can't vote on its own removal");
 
             let hash = ("remove", &address).using_encoded(<T as system::Trait>::Hashing::hash);
+            Self::expire_validator_proposal(hash);
 
             if !<ValidatorHistory<T>>::exists(hash) {
                 let message = ValidatorMessage {
@@ -167,16 +741,43 @@ decl_module! {
             }
 
             let id = <TransferId<T>>::get(hash);
-            Self::_sign(id)
+            Self::_sign(validator, id)
+        }
+
+        // each validator calls it to change the validators maximum
+        fn set_max_validators(origin, max: u32) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::check_validator(validator.clone())?;
+
+            ensure!(max <= MAX_VALIDATORS, "Validators maximum is above the hard limit.");
+            ensure!(max >= <ValidatorsCount<T>>::get(), "Validators maximum is below current validators count.");
+            let hash = ("max_validators", max).using_encoded(<T as system::Trait>::Hashing::hash);
+
+            // closed proposals are forgotten, so the same value can be proposed again
+            if !<TransferId<T>>::exists(hash) {
+                let message = LimitMessage {
+                    message_id: hash,
+                    account: validator,
+                    limit: max,
+                    action: Status::SetMaxValidators,
+                    status: Status::SetMaxValidators,
+                };
+                <LimitMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Limits)?;
+            }
+
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
         }
 
         // each validator calls it to pause the bridge
         fn pause_bridge(origin) -> Result {
             let validator = ensure_signed(origin)?;
-            Self::check_validator(validator.clone())?;
+            Self::check_pause_validator(validator.clone())?;
 
             ensure!(Self::bridge_is_operational(), "Bridge is not operational already");
-            let hash = ("pause", T::BlockNumber::sa(0)).using_encoded(<T as system::Trait>::Hashing::hash);
+            let hash = ("pause", Self::pause_epoch()).using_encoded(<T as system::Trait>::Hashing::hash);
 
             if !<BridgeMessages<T>>::exists(hash) {
                 let message = BridgeMessage {
@@ -190,15 +791,17 @@ decl_module! {
             }
 
             let id = <TransferId<T>>::get(hash);
-            Self::_sign(id)
+            Self::_sign(validator, id)
         }
 
         // each validator calls it to resume the bridge
         fn resume_bridge(origin) -> Result {
             let validator = ensure_signed(origin)?;
-            Self::check_validator(validator.clone())?;
+            Self::check_pause_validator(validator.clone())?;
 
-            let hash = ("resume", T::BlockNumber::sa(0)).using_encoded(<T as system::Trait>::Hashing::hash);
+            ensure!(!Self::bridge_is_operational(), "Bridge is already operational");
+
+            let hash = ("resume", Self::pause_epoch()).using_encoded(<T as system::Trait>::Hashing::hash);
 
             if !<BridgeMessages<T>>::exists(hash) {
                 let message = BridgeMessage {
@@ -212,25 +815,39 @@ decl_module! {
             }
 
             let id = <TransferId<T>>::get(hash);
-            Self::_sign(id)
+            Self::_sign(validator, id)
         }
 
-        //confirm burn from validator
-        fn confirm_transfer(origin, message_id: T::Hash) -> Result {
+        //confirm burn from validator, pointing at the ethereum unlock tx
+        fn confirm_transfer(origin, message_id: T::Hash, eth_unlock_tx: H256) -> Result {
             let validator = ensure_signed(origin)?;
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
-            Self::check_validator(validator)?;
+            Self::check_validator(validator.clone())?;
 
-            let id = <TransferId<T>>::get(message_id);
+            Self::_confirm(validator, message_id, eth_unlock_tx)
+        }
 
-            let is_approved = <TransferMessages<T>>::get(message_id).status == Status::Approved ||
-            <TransferMessages<T>>::get(message_id).status == Status::Confirmed;
-            ensure!(is_approved, "This transfer must be approved first.");
+        // approve a withdraw and confirm its burn in one call when state allows
+        fn approve_and_confirm(origin, message_id: T::Hash, eth_unlock_tx: H256) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::check_validator(validator.clone())?;
 
-            Self::update_status(message_id, Status::Confirmed, Kind::Transfer)?;
-            Self::reopen_for_burn_confirmation(message_id)?;
-            Self::_sign(id)?;
+            ensure!(
+                <TransferMessages<T>>::exists(message_id),
+                "Transfer message does not exist"
+            );
+            let message = <TransferMessages<T>>::get(message_id);
+            ensure!(message.action == Status::Withdraw, "Use multi_signed_mint for deposits");
 
+            if !Self::is_approved(message_id) {
+                let id = <TransferId<T>>::get(message_id);
+                Self::_sign(validator.clone(), id)?;
+            }
+            // confirmation still needs its own quorum
+            if Self::is_approved(message_id) {
+                Self::_confirm(validator, message_id, eth_unlock_tx)?;
+            }
             Ok(())
         }
 
@@ -240,371 +857,8152 @@ decl_module! {
             ensure!(Self::bridge_is_operational(), "Bridge is not operational");
             Self::check_validator(validator)?;
 
-            let mut message = <TransferMessages<T>>::get(message_id);
-            message.status = Status::Canceled;
+            ensure!(
+                <TransferMessages<T>>::exists(message_id),
+                "Transfer message does not exist"
+            );
+            let message = <TransferMessages<T>>::get(message_id);
+            ensure!(message.action == Status::Withdraw, "Only withdraws can be cancelled");
+            Self::check_closable(message_id)?;
 
-            <token::Module<T>>::unlock(&message.substrate_address, message.amount)?;
-            <TransferMessages<T>>::insert(message_id, message);
+            Self::close_transfer(message_id)
+        }
 
+        // unlock the sender's share of an escrowed mint
+        fn claim_minted(origin, message_id: T::Hash) -> Result {
+            let who = ensure_signed(origin)?;
+            let key = (message_id, who.clone());
+            let amount = Self::escrowed_mint(&key);
+            ensure!(amount > 0, "No escrowed funds to claim");
+
+            <token::Module<T>>::unlock(&who, amount)?;
+            <EscrowedMints<T>>::remove(&key);
             Ok(())
         }
-    }
-}
-
-impl<T: Trait> Module<T> {
-    fn _sign(transfer_id: ProposalId) -> Result {
-        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
 
-        let mut message = <TransferMessages<T>>::get(transfer.message_id);
-        let mut validator_message = <ValidatorHistory<T>>::get(transfer.message_id);
-        let mut bridge_message = <BridgeMessages<T>>::get(transfer.message_id);
-        ensure!(transfer.open, "This transfer is not open");
-        transfer.votes += 1;
+        // unlock refunds of cancelled withdraws credited to the sender
+        fn claim_refund(origin) -> Result {
+            let who = ensure_signed(origin)?;
+            let amount = Self::claimable_refund(&who);
+            ensure!(amount > 0, "No refund to claim");
 
-        if Self::votes_are_enough(transfer.votes) {
-            match message.status {
-                Status::Confirmed => (), // if burn is confirmed
-                _ => match transfer.kind {
-                    Kind::Transfer => message.status = Status::Approved,
-                    Kind::Validator => validator_message.status = Status::Approved,
-                    Kind::Bridge => bridge_message.status = Status::Approved,
-                },
-            }
-            match transfer.kind {
-                Kind::Transfer => Self::execute_transfer(message)?,
-                Kind::Validator => Self::manage_validator(validator_message)?,
-                Kind::Bridge => Self::manage_bridge(bridge_message)?,
-            }
-            transfer.open = false;
-        } else {
-            match message.status {
-                Status::Confirmed => (),
-                _ => Self::update_status(
-                    transfer.message_id,
-                    Status::Pending,
-                    transfer.clone().kind,
-                )?,
-            };
+            <token::Module<T>>::unlock(&who, amount)?;
+            <ClaimableRefunds<T>>::remove(&who);
+            Ok(())
         }
 
-        <BridgeTransfers<T>>::insert(transfer_id, transfer);
+        // each validator calls it to close a stuck transfer, refunding locked funds
+        fn force_close_transfer(origin, message_id: T::Hash) -> Result {
+            let validator = ensure_signed(origin)?;
+            Self::check_validator(validator.clone())?;
 
-        Ok(())
-    }
+            Self::check_closable(message_id)?;
+            let hash = ("force_close", message_id).using_encoded(<T as system::Trait>::Hashing::hash);
 
-    ///ensure that such transfer exist
-    fn get_transfer_id_checked(transfer_hash: T::Hash, kind: Kind) -> Result {
-        if !<TransferId<T>>::exists(transfer_hash) {
-            Self::create_transfer(transfer_hash, kind)?;
+            if !<CancelMessages<T>>::exists(hash) {
+                let message = CancelMessage {
+                    message_id: hash,
+                    transfer_hash: message_id,
+                    account: validator,
+                    action: Status::ForceClose,
+                    status: Status::ForceClose,
+                };
+                <CancelMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Cancel)?;
+            }
+
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
         }
 
-        Ok(())
-    }
+        // each validator calls it to confirm a fraud report, the transfer is cancelled
+        // and the reporter gets the bounty once the quorum agrees
+        fn confirm_fraud(origin, message_id: T::Hash) -> Result {
+            let validator = ensure_signed(origin)?;
+            Self::check_validator(validator.clone())?;
 
-    fn pause_the_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
-        <BridgeIsOperational<T>>::mutate(|x| *x = false);
-        Self::update_status(message.message_id, Status::Confirmed, Kind::Bridge)
-    }
+            Self::check_closable(message_id)?;
+            ensure!(<FraudReports<T>>::exists(message_id), "Transfer is not reported");
+            let hash = ("confirm_fraud", message_id)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
 
-    fn resume_the_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
-        <BridgeIsOperational<T>>::mutate(|x| *x = true);
-        Self::update_status(message.message_id, Status::Confirmed, Kind::Bridge)
-    }
+            if !<CancelMessages<T>>::exists(hash) {
+                let message = CancelMessage {
+                    message_id: hash,
+                    transfer_hash: message_id,
+                    account: validator,
+                    action: Status::ConfirmFraud,
+                    status: Status::ConfirmFraud,
+                };
+                <CancelMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Cancel)?;
+            }
 
-    /// add validator
-    fn _add_validator(info: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
-        ensure!(<ValidatorsCount<T>>::get() < MAX_VALIDATORS, "Validators maximum reached.");
-        <Validators<T>>::insert(info.account, true);
-        <ValidatorsCount<T>>::mutate(|x| *x += 1);
-        Self::update_status(info.message_id, Status::Confirmed, Kind::Validator)
-    }
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
+        }
 
-    /// remove validator
-    fn _remove_validator(info: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
-        ensure!(<ValidatorsCount<T>>::get() > 1, "Can not remove last validator.");
-        <Validators<T>>::remove(info.account);
-        <ValidatorsCount<T>>::mutate(|x| *x -= 1);
-        <ValidatorHistory<T>>::remove(info.message_id);
-        Ok(())
-    }
+        // each validator calls it to abort a burn confirmation started in error
+        fn cancel_burn_confirmation(origin, message_id: T::Hash) -> Result {
+            let validator = ensure_signed(origin)?;
+            ensure!(Self::bridge_is_operational(), "Bridge is not operational");
+            Self::check_validator(validator.clone())?;
 
-    /// check votes validity
-    fn votes_are_enough(votes: MemberId) -> bool {
-        votes as f64 / Self::validators_count() as f64 >= 0.51
-    }
+            Self::check_burn_confirmation_in_progress(message_id)?;
+            let hash = ("cancel_burn_confirmation", message_id)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
 
-    /// lock funds after set_transfer call
-    fn lock_for_burn(account: T::AccountId, amount: TokenBalance) -> Result {
-        <token::Module<T>>::lock(account, amount)?;
+            if !<TransferId<T>>::exists(hash) {
+                let message = CancelMessage {
+                    message_id: hash,
+                    transfer_hash: message_id,
+                    account: validator,
+                    action: Status::CancelBurnConfirmation,
+                    status: Status::CancelBurnConfirmation,
+                };
+                <CancelMessages<T>>::insert(hash, message);
+                Self::get_transfer_id_checked(hash, Kind::Cancel)?;
+            }
 
-        Ok(())
-    }
+            let id = <TransferId<T>>::get(hash);
+            Self::_sign(validator, id)
+        }
 
-    fn execute_burn(message_id: T::Hash) -> Result {
-        let message = <TransferMessages<T>>::get(message_id);
-        let from = message.substrate_address.clone();
-        let to = message.eth_address;
+        // hand the operator role over to another account
+        fn set_operator(origin, operator: T::AccountId) -> Result {
+            Self::ensure_operator(origin)?;
+            <Operator<T>>::put(operator);
+            Ok(())
+        }
 
-        <token::Module<T>>::unlock(&from, message.amount)?;
-        <token::Module<T>>::_burn(from.clone(), message.amount)?;
+        // pause or unpause a single direction of the bridge
+        fn set_direction_paused(origin, direction: Direction, paused: bool) -> Result {
+            Self::ensure_operator(origin)?;
+            match direction {
+                Direction::Deposit => <DepositsPaused<T>>::put(paused),
+                Direction::Withdraw => <WithdrawsPaused<T>>::put(paused),
+            }
+            Ok(())
+        }
 
-        Self::deposit_event(RawEvent::Burned(message_id, from, to, message.amount));
-        Ok(())
-    }
+        // replace the whole validator set, meant for early stage setup
+        fn set_validators(origin, accounts: Vec<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            Self::replace_validator_set(accounts)
+        }
 
-    fn execute_transfer(message: TransferMessage<T::AccountId, T::Hash>) -> Result {
-        match message.action {
-            Status::Deposit => match message.status {
-                Status::Approved => {
-                    let to = message.substrate_address.clone();
-                    <token::Module<T>>::_mint(to, message.amount)?;
-                    Self::deposit_event(RawEvent::Minted(message.message_id));
-                    Self::update_status(message.message_id, Status::Confirmed, Kind::Transfer)
-                }
-                _ => Err("Tried to deposit with non-supported status"),
-            },
-            Status::Withdraw => match message.status {
-                Status::Confirmed => Self::execute_burn(message.message_id),
-                Status::Approved => {
-                    let to = message.eth_address;
-                    let from = message.substrate_address.clone();
-                    Self::lock_for_burn(from.clone(), message.amount)?;
-                    Self::deposit_event(RawEvent::ApprovedRelayMessage(
-                        message.message_id,
-                        from,
-                        to,
-                        message.amount,
-                    ));
-                    Self::update_status(message.message_id, Status::Approved, Kind::Transfer)
-                }
-                _ => Err("Tried to withdraw with non-supported status"),
-            },
-            _ => Err("Tried to execute transfer with non-supported status"),
+        // super admins vote to replace the validator set when validators can't reach quorum,
+        // votes of the replaced validators stop counting on open proposals
+        fn super_replace_validators(origin, accounts: Vec<T::AccountId>) -> Result {
+            let admin = ensure_signed(origin)?;
+            Self::check_validator_set(&accounts)?;
+
+            let hash = ("super_replace_validators", Self::super_admin_epoch(), &accounts)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
+            if Self::super_admin_vote(admin, hash)? {
+                Self::replace_validator_set(accounts)?;
+            }
+            Ok(())
         }
-    }
 
-    fn manage_validator(message: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
-        match message.action {
-            Status::AddValidator => match message.status {
-                Status::Approved => Self::_add_validator(message),
-                _ => Err("Tried to add validator with non-supported status"),
-            },
-            Status::RemoveValidator => match message.status {
-                Status::Approved => Self::_remove_validator(message),
-                _ => Err("Tried to remove validator with non-supported status"),
-            },
-            _ => Err("Tried to manage validator with non-supported status"),
+        // super admins vote to replace their own set
+        fn super_set_admins(origin, admins: Vec<T::AccountId>) -> Result {
+            let admin = ensure_signed(origin)?;
+            ensure!(!admins.is_empty(), "Super admin set can't be empty");
+
+            let hash = ("super_set_admins", Self::super_admin_epoch(), &admins)
+                .using_encoded(<T as system::Trait>::Hashing::hash);
+            if Self::super_admin_vote(admin, hash)? {
+                <SuperAdmins<T>>::put(admins);
+            }
+            Ok(())
         }
-    }
 
-    fn manage_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
-        match message.action {
-            Status::PauseTheBridge => match message.status {
-                Status::Approved => Self::pause_the_bridge(message),
-                _ => Err("Tried to pause the bridge with non-supported status"),
-            },
-            Status::ResumeTheBridge => match message.status {
-                Status::Approved => Self::resume_the_bridge(message),
-                _ => Err("Tried to resume the bridge with non-supported status"),
-            },
-            _ => Err("Tried to manage bridge with non-supported status"),
+        // set voting weight of a validator
+        fn set_validator_weight(origin, validator: T::AccountId, weight: u32) -> Result {
+            ensure_root(origin)?;
+            Self::check_validator(validator.clone())?;
+            ensure!(weight > 0, "Validator weight must be positive");
+
+            let old_weight = <ValidatorWeight<T>>::get(&validator);
+            // shadow weight joins the total on promotion
+            if !Self::is_shadow_validator(&validator) {
+                let total = Self::total_validator_weight()
+                    .checked_sub(old_weight)
+                    .ok_or("Validator weight exceeds the total")?
+                    .checked_add(weight)
+                    .ok_or("Overflow adding validator weight")?;
+                <TotalValidatorWeight<T>>::put(total);
+            }
+            <ValidatorWeight<T>>::insert(validator, weight);
+            Ok(())
         }
-    }
 
-    fn create_transfer(transfer_hash: T::Hash, kind: Kind) -> Result {
-        ensure!(
-            !<TransferId<T>>::exists(transfer_hash),
-            "This transfer already open"
-        );
+        // run validator in shadow mode or promote it back to a voting one
+        fn set_shadow_validator(origin, validator: T::AccountId, shadow: bool) -> Result {
+            ensure_root(origin)?;
+            Self::check_validator(validator.clone())?;
+            ensure!(Self::is_shadow_validator(&validator) != shadow, "Shadow mode is already set");
 
-        let transfer_id = <BridgeTransfersCount<T>>::get();
-        let bridge_transfers_count = <BridgeTransfersCount<T>>::get();
-        let new_bridge_transfers_count = bridge_transfers_count
-            .checked_add(1)
-            .ok_or("Overflow adding a new bridge transfer")?;
+            let weight = <ValidatorWeight<T>>::get(&validator);
+            if shadow {
+                ensure!(
+                    Self::total_validator_weight() > weight,
+                    "Can not shadow the last voting validator"
+                );
+                <TotalValidatorWeight<T>>::mutate(|x| *x -= weight);
+                <ShadowValidators<T>>::insert(validator, true);
+            } else {
+                let total = Self::total_validator_weight()
+                    .checked_add(weight)
+                    .ok_or("Overflow adding validator weight")?;
+                <TotalValidatorWeight<T>>::put(total);
+                <ShadowValidators<T>>::remove(validator);
+            }
+            Ok(())
+        }
 
-        let transfer = BridgeTransfer {
-            transfer_id,
-            message_id: transfer_hash,
-            open: true,
-            votes: 0,
-            kind,
-        };
+        // set extra votes for large transfers, thresholds in increasing order
+        fn set_amount_tiers(origin, tiers: Vec<(TokenBalance, MemberId)>) -> Result {
+            ensure_root(origin)?;
+            ensure!(tiers.len() <= MAX_AMOUNT_TIERS, "Too many amount tiers");
+            let is_sorted = tiers.windows(2).all(|pair| pair[0].0 < pair[1].0);
+            ensure!(is_sorted, "Amount tiers must be in increasing order");
 
-        <BridgeTransfers<T>>::insert(transfer_id, transfer);
-        <BridgeTransfersCount<T>>::mutate(|count| *count = new_bridge_transfers_count);
-        <TransferId<T>>::insert(transfer_hash, transfer_id);
-        <MessageId<T>>::insert(transfer_id, transfer_hash);
+            <AmountTiers<T>>::put(tiers);
+            Ok(())
+        }
 
-        Ok(())
-    }
+        // set how close to the minimum the validator set may get before warning
+        fn set_validator_set_low_margin(origin, margin: u32) -> Result {
+            ensure_root(origin)?;
+            <ValidatorSetLowMargin<T>>::put(margin);
+            Ok(())
+        }
 
-    fn update_status(id: T::Hash, status: Status, kind: Kind) -> Result {
-        match kind {
-            Kind::Transfer => {
-                let mut message = <TransferMessages<T>>::get(id);
-                message.status = status;
-                <TransferMessages<T>>::insert(id, message);
-            }
-            Kind::Validator => {
-                let mut message = <ValidatorHistory<T>>::get(id);
-                message.status = status;
-                <ValidatorHistory<T>>::insert(id, message);
+        // set minimal number of signatures regardless of percentage
+        fn set_min_absolute_votes(origin, votes: MemberId) -> Result {
+            ensure_root(origin)?;
+            ensure!(votes > 0, "Minimal votes must be positive");
+            <MinAbsoluteVotes<T>>::put(votes);
+            Ok(())
+        }
+
+        fn set_validator_add_delay(origin, delay: T::BlockNumber) -> Result {
+            ensure_root(origin)?;
+            <ValidatorAddDelay<T>>::put(delay);
+            Ok(())
+        }
+
+        // set lifetime of validator add/remove proposals
+        fn set_validator_proposal_ttl(origin, ttl: T::BlockNumber) -> Result {
+            ensure_root(origin)?;
+            <ValidatorProposalTtl<T>>::put(ttl);
+            Ok(())
+        }
+
+        fn set_recent_events_retention(origin, retention: T::BlockNumber) -> Result {
+            ensure_root(origin)?;
+            <RecentEventsRetention<T>>::put(retention);
+            Ok(())
+        }
+
+        // re-check up to limit open proposals against the current validator set,
+        // the next call continues where this one stopped
+        fn reconcile_all(origin, limit: u32) -> Result {
+            ensure_root(origin)?;
+            ensure!(limit > 0, "Limit must be positive");
+
+            // going down from the top, closed proposals only move checked ones
+            let mut cursor = Self::reconcile_cursor();
+            if cursor == 0 {
+                cursor = Self::open_proposals_count();
             }
-            Kind::Bridge => {
-                let mut message = <BridgeMessages<T>>::get(id);
-                message.status = status;
-                <BridgeMessages<T>>::insert(id, message);
+            let mut checked = 0;
+            while cursor > 0 && checked < limit {
+                cursor -= 1;
+                checked += 1;
+                // executions may have removed proposals from the top
+                if cursor < Self::open_proposals_count() {
+                    Self::reconcile_proposal(Self::open_proposal_at(cursor));
+                }
             }
+            <ReconcileCursor<T>>::put(cursor);
+            Ok(())
         }
-        Ok(())
-    }
-    fn reopen_for_burn_confirmation(message_id: T::Hash) -> Result {
-        let message = <TransferMessages<T>>::get(message_id);
-        let transfer_id = <TransferId<T>>::get(message_id);
-        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
-        if !transfer.open && message.status == Status::Confirmed {
-            transfer.votes = 0;
-            transfer.open = true;
-            <BridgeTransfers<T>>::insert(transfer_id, transfer);
-        }
-        Ok(())
-    }
-    fn check_validator(validator: T::AccountId) -> Result {
-        let is_trusted = <Validators<T>>::exists(validator);
-        ensure!(is_trusted, "Only validators can call this function");
 
-        Ok(())
-    }
-}
+        // deadline given to proposals created from now on
+        fn set_proposal_ttl(origin, ttl: T::BlockNumber) -> Result {
+            ensure_root(origin)?;
+            <ProposalTtl<T>>::put(ttl);
+            Ok(())
+        }
 
-/// tests for this module
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // move the deadline of an open proposal
+        fn set_proposal_deadline(
+            origin,
+            transfer_id: ProposalId,
+            deadline: T::BlockNumber
+        ) -> Result {
+            ensure_root(origin)?;
+            ensure!(<BridgeTransfers<T>>::exists(transfer_id), "Proposal does not exist");
+            ensure!(<BridgeTransfers<T>>::get(transfer_id).open, "Proposal is not open");
+            ensure!(deadline > <system::Module<T>>::block_number(), "Deadline is in the past");
 
-    use primitives::{Blake2Hasher, H160, H256};
-    use runtime_io::with_externalities;
-    use runtime_primitives::{
-        testing::{Digest, DigestItem, Header},
-        traits::{BlakeTwo256, IdentityLookup},
-        BuildStorage,
-    };
-    use support::{assert_noop, assert_ok, impl_outer_origin};
+            Self::schedule_deadline(transfer_id, deadline);
+            Ok(())
+        }
 
-    impl_outer_origin! {
-        pub enum Origin for Test {}
-    }
+        // set account receiving bridge fees
+        fn set_fee_account(origin, account: T::AccountId) -> Result {
+            ensure_root(origin)?;
+            <FeeAccount<T>>::put(account);
+            Ok(())
+        }
 
-    // For testing the module, we construct most of a mock runtime. This means
-    // first constructing a configuration type (`Test`) which `impl`s each of the
-    // configuration traits of modules we want to use.
-    #[derive(Clone, Eq, PartialEq)]
-    pub struct Test;
-    impl system::Trait for Test {
-        type Origin = Origin;
-        type Index = u64;
-        type BlockNumber = u64;
-        type Hash = H256;
-        type Hashing = BlakeTwo256;
-        type Digest = Digest;
-        type AccountId = u64;
-        type Lookup = IdentityLookup<Self::AccountId>;
-        type Header = Header;
-        type Event = ();
-        type Log = DigestItem;
-    }
-    impl balances::Trait for Test {
-        type Balance = u128;
-        type OnFreeBalanceZero = ();
-        type OnNewAccount = ();
-        type TransactionPayment = ();
-        type TransferPayment = ();
-        type DustRemoval = ();
-        type Event = ();
-    }
-    impl timestamp::Trait for Test {
-        type Moment = u64;
-        type OnTimestampSet = ();
-    }
-    impl token::Trait for Test {
-        type Event = ();
-    }
-    impl Trait for Test {
-        type Event = ();
-    }
+        // set account receiving mint fees, None falls back to fee_account
+        fn set_mint_fee_account(origin, account: Option<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            match account {
+                Some(account) => <MintFeeAccount<T>>::put(account),
+                None => <MintFeeAccount<T>>::kill(),
+            }
+            Ok(())
+        }
 
-    type BridgeModule = Module<Test>;
-    type TokenModule = token::Module<Test>;
+        // set account receiving burn fees, None falls back to fee_account
+        fn set_burn_fee_account(origin, account: Option<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            match account {
+                Some(account) => <BurnFeeAccount<T>>::put(account),
+                None => <BurnFeeAccount<T>>::kill(),
+            }
+            Ok(())
+        }
 
-    const ETH_MESSAGE_ID: &[u8; 32] = b"0x5617efe391571b5dc8230db92ba65b";
-    const ETH_ADDRESS: &[u8; 20] = b"0x00b46c2526ebb8f4c9";
-    const V1: u64 = 1;
-    const V2: u64 = 2;
-    const V3: u64 = 3;
-    const V4: u64 = 4;
-    const USER1: u64 = 4;
-    const USER2: u64 = 5;
+        // turn withdraw whitelist on or off
+        fn set_withdraw_whitelist_enabled(origin, enabled: bool) -> Result {
+            Self::ensure_operator(origin)?;
+            <WithdrawWhitelistEnabled<T>>::put(enabled);
+            Ok(())
+        }
 
-    // This function basically just builds a genesis storage key/value store according to
-    // our desired mockup.
-    fn new_test_ext() -> runtime_io::TestExternalities<Blake2Hasher> {
-        let mut r = system::GenesisConfig::<Test>::default()
-            .build_storage()
-            .unwrap()
-            .0;
+        // add account to or remove it from withdraw whitelist
+        fn set_withdraw_whitelisted(origin, account: T::AccountId, allowed: bool) -> Result {
+            Self::ensure_operator(origin)?;
+            if allowed {
+                <WithdrawWhitelist<T>>::insert(account, true);
+            } else {
+                <WithdrawWhitelist<T>>::remove(account);
+            }
+            Ok(())
+        }
 
-        r.extend(
-            balances::GenesisConfig::<Test> {
-                balances: vec![
-                    (V1, 100000),
-                    (V2, 100000),
-                    (V3, 100000),
-                    (USER1, 100000),
-                    (USER2, 300000),
-                ],
-                vesting: vec![],
-                transaction_base_fee: 0,
-                transaction_byte_fee: 0,
-                existential_deposit: 500,
-                transfer_fee: 0,
-                creation_fee: 0,
+        // mark ethereum address as reserved by the bridge or release it
+        fn set_reserved_eth_address(origin, address: H160, reserved: bool) -> Result {
+            Self::ensure_operator(origin)?;
+            if reserved {
+                <ReservedEthAddresses<T>>::insert(address, true);
+            } else {
+                <ReservedEthAddresses<T>>::remove(address);
             }
-            .build_storage()
-            .unwrap()
-            .0,
-        );
+            Ok(())
+        }
 
-        r.extend(
-            GenesisConfig::<Test> {
-                validators_count: 3u32,
-                validator_accounts: vec![V1, V2, V3],
+        // trust ethereum key to sign lock events or revoke it
+        fn set_eth_signer(origin, address: H160, trusted: bool) -> Result {
+            ensure_root(origin)?;
+            if trusted {
+                <EthSigners<T>>::insert(address, true);
+            } else {
+                <EthSigners<T>>::remove(address);
             }
-            .build_storage()
-            .unwrap()
-            .0,
-        );
+            Ok(())
+        }
 
-        r.into()
+        // require a trusted ethereum signature on every mint vote
+        fn set_mint_proof_required(origin, required: bool) -> Result {
+            ensure_root(origin)?;
+            <MintProofRequired<T>>::put(required);
+            Ok(())
+        }
+
+        fn set_mint_validators(origin, validators: Vec<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            Self::check_authority_set(&validators)?;
+            <MintValidators<T>>::put(validators);
+            Ok(())
+        }
+
+        fn set_pause_validators(origin, validators: Vec<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            Self::check_authority_set(&validators)?;
+            <PauseValidators<T>>::put(validators);
+            Ok(())
+        }
+
+        fn set_reserved_mint_recipients(origin, accounts: Vec<T::AccountId>) -> Result {
+            ensure_root(origin)?;
+            <ReservedMintRecipients<T>>::put(accounts);
+            Ok(())
+        }
+
+        fn set_mint_escrow(origin, enabled: bool) -> Result {
+            ensure_root(origin)?;
+            <MintEscrowEnabled<T>>::put(enabled);
+            Ok(())
+        }
+
+        fn set_validator_bond(origin, bond: T::Balance) -> Result {
+            ensure_root(origin)?;
+            <ValidatorBond<T>>::put(bond);
+            Ok(())
+        }
+
+        // credit refunds of cancelled withdraws for claim_refund instead of unlocking them
+        fn set_claimable_refunds(origin, enabled: bool) -> Result {
+            ensure_root(origin)?;
+            <ClaimableRefundsEnabled<T>>::put(enabled);
+            Ok(())
+        }
+
+        // turn recording of ethereum senders on or off
+        fn set_track_eth_senders(origin, enabled: bool) -> Result {
+            ensure_root(origin)?;
+            <TrackEthSenders<T>>::put(enabled);
+            Ok(())
+        }
+
+        // end bootstrap phase for good
+        fn finalize_bootstrap(origin) -> Result {
+            ensure_root(origin)?;
+            ensure!(Self::bootstrapping(), "Bootstrap is already finalized");
+            <Bootstrapping<T>>::put(false);
+            Ok(())
+        }
+
+        fn set_no_validator_self_mint(origin, enabled: bool) -> Result {
+            ensure_root(origin)?;
+            <NoValidatorSelfMint<T>>::put(enabled);
+            Ok(())
+        }
+
+        // set the smallest transfer in base units
+        fn set_min_transfer_amount(origin, #[compact] amount: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            <MinTransferAmount<T>>::put(amount);
+            Ok(())
+        }
+
+        // set the smallest transfer in whole tokens
+        fn set_min_transfer_amount_in_tokens(origin, #[compact] tokens: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            let amount = Self::tokens_to_base_units(tokens)?;
+            <MinTransferAmount<T>>::put(amount);
+            Ok(())
+        }
+
+        // set max age in seconds of minted lock events, 0 disables the check
+        fn set_max_mint_age(origin, age: u64) -> Result {
+            ensure_root(origin)?;
+            <MaxMintAge<T>>::put(age);
+            Ok(())
+        }
+
+        // set number of failed retry_mint calls after which a mint is cancelled
+        fn set_max_mint_retries(origin, retries: u32) -> Result {
+            ensure_root(origin)?;
+            <MaxMintRetries<T>>::put(retries);
+            Ok(())
+        }
+
+        // recovery tool for inconsistent locked accounting, only while the bridge is paused
+        fn force_set_locked(
+            origin,
+            account: T::AccountId,
+            #[compact] amount: TokenBalance
+        ) -> Result {
+            ensure_root(origin)?;
+            ensure!(!Self::bridge_is_operational(), "Bridge must be paused");
+
+            let old_locked = <token::Module<T>>::locked(&account);
+            <token::Module<T>>::set_locked(&account, amount)?;
+            Self::deposit_event(RawEvent::LockedBalanceCorrected(account, old_locked, amount));
+            Ok(())
+        }
+
+        // set cap on total supply minted by the bridge
+        fn set_max_total_supply(origin, #[compact] max: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            <MaxTotalSupply<T>>::put(max);
+            Ok(())
+        }
+
+        fn set_min_reserve_ratio(origin, percent: u32) -> Result {
+            ensure_root(origin)?;
+            <MinReserveRatio<T>>::put(percent);
+            Ok(())
+        }
+
+        fn report_collateral(origin, #[compact] collateral: TokenBalance) -> Result {
+            Self::ensure_operator(origin)?;
+            <ReportedCollateral<T>>::put(collateral);
+            Ok(())
+        }
+
+        // look at most `limit` transfers from the cursor and remove the finalized ones
+        // created before `before_block`, open ones on the way count against the limit too
+        fn prune_finalized(origin, before_block: T::BlockNumber, limit: u32) -> Result {
+            ensure_root(origin)?;
+            ensure!(limit as usize <= MAX_BATCH_SIZE, "Batch is too large");
+
+            let mut cursor = Self::prune_cursor();
+            // an open transfer holds the cursor, so calls resume where the last one stopped
+            let start = cursor.max(Self::prune_scan_cursor());
+            let count = Self::bridge_transfers_count();
+            let end = count.min(start.saturating_add(ProposalId::from(limit)));
+            let mut next = start;
+            for id in start..end {
+                if Self::transfer_created_at(id) >= before_block {
+                    break;
+                }
+                if <BridgeTransfers<T>>::exists(id) && Self::is_finalized_transfer(id) {
+                    Self::prune_transfer(id);
+                }
+                // the cursor stops at the first transfer which may still be pruned later
+                if cursor == id && !Self::is_prunable_later(id) {
+                    cursor = id + 1;
+                }
+                next = id + 1;
+            }
+            <PruneCursor<T>>::put(cursor);
+            // a scan which reached the newest or too recent transfers starts over
+            let scan = if next == end && end < count { next } else { 0 };
+            <PruneScanCursor<T>>::put(scan);
+            Ok(())
+        }
+
+        // set bounty paid for a report of a transfer which got cancelled
+        fn set_fraud_bounty(origin, #[compact] bounty: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            <FraudBounty<T>>::put(bounty);
+            Ok(())
+        }
+
+        // set fee charged on ethereum -> substrate transfers
+        fn set_mint_fee(origin, #[compact] fee: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            <MintFee<T>>::put(fee);
+            Ok(())
+        }
+
+        // set fee charged on substrate -> ethereum transfers
+        fn set_withdraw_fee(origin, #[compact] fee: TokenBalance) -> Result {
+            ensure_root(origin)?;
+            <WithdrawFee<T>>::put(fee);
+            Ok(())
+        }
+
+        fn set_indexing_hints(origin, enabled: bool) -> Result {
+            ensure_root(origin)?;
+            <IndexingHints<T>>::put(enabled);
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// id that will be assigned to the next created proposal
+    pub fn next_proposal_id() -> ProposalId {
+        <BridgeTransfersCount<T>>::get()
+    }
+
+    /// page of proposals by id, at most MAX_TRANSFERS_PAGE long, pruned ids are skipped
+    pub fn transfers_paginated(start: ProposalId, count: u32) -> Vec<(ProposalId, BridgeTransfer<T::Hash>)> {
+        let count = ProposalId::from(count.min(MAX_TRANSFERS_PAGE));
+        let end = start
+            .saturating_add(count)
+            .min(Self::bridge_transfers_count());
+        (start..end)
+            .filter(|id| <BridgeTransfers<T>>::exists(id))
+            .map(|id| (id, Self::transfers(id)))
+            .collect()
+    }
+
+    /// ids of proposals that are still open, oldest first
+    pub fn open_proposals() -> Vec<ProposalId> {
+        let mut ids: Vec<ProposalId> = (0..Self::open_proposals_count())
+            .map(Self::open_proposal_at)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// open validator additions and removals
+    pub fn pending_validator_changes() -> Vec<(ProposalId, ValidatorMessage<T::AccountId, T::Hash>)> {
+        Self::open_proposals()
+            .into_iter()
+            .map(|id| (id, Self::transfers(id)))
+            .filter_map(|(id, transfer)| match transfer.kind {
+                Kind::Validator => Some((id, <ValidatorHistory<T>>::get(transfer.message_id))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// votes weight needed to execute a proposal with the current validator set
+    pub fn required_votes() -> MemberId {
+        Self::required_votes_of(Self::total_validator_weight())
+    }
+
+    /// votes weight needed to execute a transfer of amount
+    pub fn required_votes_for(amount: TokenBalance) -> MemberId {
+        Self::required_votes_for_amount(Self::total_validator_weight(), amount)
+    }
+
+    /// transfer message ids with the status, in no particular order
+    pub fn transfers_with_status(status: Status) -> Vec<T::Hash> {
+        (0..Self::transfers_with_status_count(&status))
+            .map(|position| Self::transfer_by_status((status.clone(), position)))
+            .collect()
+    }
+
+    /// balance, locked funds and withdraws of the account still in progress, oldest first;
+    /// the runtime has no daily withdraw limit or cooldown, so there's nothing to report on them
+    pub fn account_position(account: &T::AccountId) -> AccountPosition<T::Hash> {
+        let pending_withdraws = Self::open_withdraws(account);
+        let pending_amount = pending_withdraws
+            .iter()
+            .fold(0, |sum: TokenBalance, id| sum.saturating_add(Self::messages(id).amount));
+
+        AccountPosition {
+            balance: <token::Module<T>>::balance_of(account),
+            locked: <token::Module<T>>::locked(account),
+            pending_withdraws,
+            pending_amount,
+        }
+    }
+
+    /// ValidatorsCount minus validators actually listed, non-zero means the counter drifted
+    pub fn validator_count_discrepancy() -> i64 {
+        let actual = Self::validator_accounts()
+            .into_iter()
+            .filter(|account| Self::validators(account.clone()))
+            .count();
+        i64::from(Self::validators_count()) - actual as i64
+    }
+
+    /// message id set_transfer assigns to a withdraw, nonce is withdraw_nonce of the sender
+    pub fn compute_transfer_hash(
+        from: &T::AccountId,
+        to: H160,
+        amount: TokenBalance,
+        nonce: u64,
+    ) -> T::Hash {
+        (from, to, amount, nonce).using_encoded(<T as system::Trait>::Hashing::hash)
+    }
+
+    /// ethereum address a completed mint came from
+    pub fn mint_source(message_id: T::Hash) -> Option<H160> {
+        if !<TransferMessages<T>>::exists(message_id) {
+            return None;
+        }
+        let message = Self::messages(message_id);
+        match (message.action, message.status) {
+            (Status::Deposit, Status::Confirmed) => Some(message.eth_address),
+            _ => None,
+        }
+    }
+
+    /// transfer transitions recorded at since_block or later, oldest first
+    pub fn recent_events(since_block: T::BlockNumber) -> Vec<EventSummary<T::Hash>> {
+        <RecentEvents<T>>::get()
+            .into_iter()
+            .filter(|(block, _)| *block >= since_block)
+            .map(|(_, summary)| summary)
+            .collect()
+    }
+
+    /// withdraw is approved and its funds stay locked until burn or cancel
+    pub fn is_locked(message_id: T::Hash) -> bool {
+        if !<TransferId<T>>::exists(message_id) {
+            return false;
+        }
+        let message = Self::messages(message_id);
+        let awaiting_burn =
+            message.status == Status::Approved || message.status == Status::Confirmed;
+        message.action == Status::Withdraw
+            && awaiting_burn
+            && Self::locked_by_proposal(<TransferId<T>>::get(message_id)) > 0
+    }
+
+    /// percent of the last window proposals signed by the account,
+    /// window is capped by MAX_PARTICIPATION_WINDOW and the proposals count
+    pub fn participation_rate(account: &T::AccountId, window: u64) -> u8 {
+        let count = Self::bridge_transfers_count();
+        let window = window.min(MAX_PARTICIPATION_WINDOW).min(count);
+        if window == 0 {
+            return 0;
+        }
+        let start = count - window;
+        let signed = Self::signed_proposals(account)
+            .into_iter()
+            .filter(|id| *id >= start)
+            .count() as u64;
+        (signed * 100 / window) as u8
+    }
+
+    /// tokens locked by a withdraw proposal, zero once burned or cancelled
+    pub fn locked_by_proposal(transfer_id: ProposalId) -> TokenBalance {
+        <BridgeTransfers<T>>::get(transfer_id).locked
+    }
+
+    /// days since unix epoch by the chain timestamp
+    pub fn current_day_index() -> u64 {
+        let now: u64 = <timestamp::Module<T>>::now().as_();
+        now / DAY_SECONDS
+    }
+
+    /// fees collected from start_day to end_day, both included
+    pub fn fees_between(start_day: u64, end_day: u64) -> TokenBalance {
+        Self::fee_days()
+            .into_iter()
+            .filter(|day| *day >= start_day && *day <= end_day)
+            .fold(0, |sum, day| sum.saturating_add(Self::daily_fees(day)))
+    }
+
+    /// fee, limits and whether the transfer would be accepted now
+    pub fn effective_params(
+        account: T::AccountId,
+        direction: Direction,
+        amount: TokenBalance,
+    ) -> EffectiveParams {
+        let supply_headroom = Self::max_total_supply()
+            .saturating_sub(<token::Module<T>>::total_supply());
+        let (fee, accepted) = match direction {
+            Direction::Deposit => (
+                Self::mint_fee().min(amount),
+                Self::check_mint(amount).is_ok() && amount <= supply_headroom,
+            ),
+            Direction::Withdraw => (
+                Self::withdraw_fee().min(amount),
+                Self::check_withdraw_amount(&account, amount).is_ok(),
+            ),
+        };
+        EffectiveParams {
+            fee,
+            min_amount: Self::min_transfer_amount(),
+            supply_headroom,
+            bootstrapping: Self::bootstrapping(),
+            allowed: Self::bridge_is_operational() && accepted,
+        }
+    }
+
+    /// quorum parameters in one bundle, the same for every proposal kind
+    pub fn quorum_config() -> QuorumConfig {
+        QuorumConfig {
+            threshold_percent: QUORUM_PERCENT,
+            min_absolute_votes: Self::min_absolute_votes(),
+            min_validators: MIN_VALIDATORS,
+            max_validators: Self::max_validators(),
+            validators_count: Self::validators_count(),
+            total_weight: Self::total_validator_weight(),
+        }
+    }
+
+    /// validators which signed the proposal, including ones removed since it closed
+    pub fn proposal_signers(transfer_id: ProposalId) -> Vec<T::AccountId> {
+        let bits = Self::signature_bits(transfer_id);
+        (0..Self::next_validator_index())
+            .filter(|index| {
+                let (byte, mask) = Self::signature_bit(*index);
+                bits.get(byte).map_or(false, |b| b & mask != 0)
+            })
+            .map(Self::indexed_validator)
+            .collect()
+    }
+
+    /// validator voted in the current phase of the proposal
+    pub fn has_signed(transfer_id: ProposalId, validator: T::AccountId) -> bool {
+        if !<ValidatorIndex<T>>::exists(&validator) {
+            return false;
+        }
+        let (byte, mask) = Self::signature_bit(Self::validator_index(validator));
+        Self::signature_bits(transfer_id)
+            .get(byte)
+            .map_or(false, |bits| bits & mask != 0)
+    }
+
+    /// direction is allowed by every pause mechanism
+    pub fn effective_operational(direction: Direction) -> bool {
+        let direction_paused = match direction {
+            Direction::Deposit => Self::deposits_paused(),
+            Direction::Withdraw => Self::withdraws_paused(),
+        };
+        Self::bridge_is_operational() && !direction_paused
+    }
+
+    /// validators without counted signatures for more than threshold_blocks
+    pub fn stale_validators(threshold_blocks: T::BlockNumber) -> Vec<T::AccountId> {
+        let now = <system::Module<T>>::block_number();
+        if now <= threshold_blocks {
+            return Vec::new();
+        }
+        let cutoff = now - threshold_blocks;
+        Self::validator_accounts()
+            .into_iter()
+            .filter(|v| <ValidatorLastActive<T>>::get(v) < cutoff)
+            .collect()
+    }
+
+    /// status of the transfer message, None if there is no such message
+    pub fn transfer_state(message_id: T::Hash) -> Option<Status> {
+        if <TransferMessages<T>>::exists(message_id) {
+            Some(<TransferMessages<T>>::get(message_id).status)
+        } else {
+            None
+        }
+    }
+
+    /// withdraw is approved, funds are locked and burn confirmation is awaited
+    pub fn is_ready_for_confirmation(message_id: T::Hash) -> bool {
+        let message = <TransferMessages<T>>::get(message_id);
+        message.action == Status::Withdraw && message.status == Status::Approved
+    }
+
+    fn _sign(validator: T::AccountId, transfer_id: ProposalId) -> Result {
+        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
+
+        let message = <TransferMessages<T>>::get(transfer.message_id);
+        let validator_message = <ValidatorHistory<T>>::get(transfer.message_id);
+        ensure!(transfer.open, "This transfer is not open");
+        ensure!(
+            !Self::has_signed(transfer_id, validator.clone()),
+            "This validator has already signed"
+        );
+        if Self::is_shadow_validator(&validator) {
+            return Self::record_shadow_vote(validator, transfer_id);
+        }
+
+        // removal target can't vote on it, so with two validators it isn't counted
+        // in the quorum and the remaining one decides alone (unanimity)
+        let mut total_weight = Self::total_validator_weight();
+        if let Kind::Validator = transfer.kind {
+            if validator_message.action == Status::RemoveValidator
+                && Self::validators_count() == 2
+            {
+                total_weight = total_weight
+                    .saturating_sub(Self::voting_weight(&validator_message.account));
+            }
+        }
+        let total_weight = Self::decisive_weight(&transfer, total_weight);
+
+        transfer.votes = transfer
+            .votes
+            .checked_add(MemberId::from(<ValidatorWeight<T>>::get(&validator)))
+            .ok_or("Vote count overflow")?;
+        transfer.signatures = transfer
+            .signatures
+            .checked_add(1)
+            .ok_or("Vote count overflow")?;
+
+        let amount = match transfer.kind {
+            Kind::Transfer => message.amount,
+            _ => 0,
+        };
+        if Self::votes_are_enough(transfer.votes, transfer.signatures, total_weight, amount) {
+            // storage isn't rolled back on error, so executions check before they write;
+            // a failed one leaves the proposal open without this vote and can be retried
+            Self::execute_proposal(&transfer)?;
+            // execution may have changed the locked amount in storage
+            transfer.locked = <BridgeTransfers<T>>::get(transfer_id).locked;
+            transfer.open = false;
+            Self::forget_open_proposal(transfer_id);
+        } else {
+            match message.status {
+                Status::Confirmed => (),
+                _ => Self::update_status(
+                    transfer.message_id,
+                    Status::Pending,
+                    transfer.clone().kind,
+                )?,
+            };
+        }
+
+        let votes = transfer.votes;
+        <BridgeTransfers<T>>::insert(transfer_id, transfer);
+        Self::set_signature_bit(transfer_id, Self::validator_index(&validator), true);
+        <ValidatorLastActive<T>>::insert(validator.clone(), <system::Module<T>>::block_number());
+        Self::record_participation(&validator, transfer_id);
+        Self::deposit_event(RawEvent::Signed(transfer_id, validator, votes));
+
+        Ok(())
+    }
+
+    fn record_participation(validator: &T::AccountId, transfer_id: ProposalId) {
+        let oldest = transfer_id.saturating_sub(MAX_PARTICIPATION_WINDOW - 1);
+        <SignedProposals<T>>::mutate(validator, |ids| {
+            ids.retain(|id| *id >= oldest);
+            ids.push(transfer_id);
+        });
+    }
+
+    /// signed by the current bridge operator
+    fn ensure_operator(origin: T::Origin) -> Result {
+        let sender = ensure_signed(origin)?;
+        ensure!(sender == Self::operator(), "Only bridge operator can call this function");
+        Ok(())
+    }
+
+    /// approve messages of a proposal which reached quorum and execute it
+    fn execute_proposal(transfer: &BridgeTransfer<T::Hash>) -> Result {
+        let mut message = <TransferMessages<T>>::get(transfer.message_id);
+        let mut validator_message = <ValidatorHistory<T>>::get(transfer.message_id);
+        let mut bridge_message = <BridgeMessages<T>>::get(transfer.message_id);
+        let mut limit_message = <LimitMessages<T>>::get(transfer.message_id);
+        let mut cancel_message = <CancelMessages<T>>::get(transfer.message_id);
+        match message.status {
+            Status::Confirmed => (), // if burn is confirmed
+            _ => match transfer.kind {
+                Kind::Transfer => message.status = Status::Approved,
+                Kind::Validator => validator_message.status = Status::Approved,
+                Kind::Bridge => bridge_message.status = Status::Approved,
+                Kind::Limits => limit_message.status = Status::Approved,
+                Kind::Cancel => cancel_message.status = Status::Approved,
+            },
+        }
+        let executed = match transfer.kind {
+            Kind::Transfer => Self::execute_transfer(message),
+            Kind::Validator => Self::manage_validator(validator_message),
+            Kind::Bridge => Self::manage_bridge(bridge_message),
+            Kind::Limits => Self::manage_limits(limit_message),
+            Kind::Cancel => Self::manage_cancel(cancel_message),
+        };
+        if executed.is_err() {
+            <FailedExecutionsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+        }
+        executed
+    }
+
+    /// validator removal lowers the quorum, so open proposals which already
+    /// have enough votes under the new set are executed right away; votes of removed
+    /// validators are taken off before, see release_validator_index;
+    /// validator proposals (the running one too) are skipped to not cascade set changes
+    fn reevaluate_open_proposals() {
+        <ReevaluationCursor<T>>::put(Self::open_proposals_count());
+        Self::continue_reevaluation();
+    }
+
+    /// re-check up to MAX_REEVALUATIONS_PER_BLOCK open proposals, the rest waits for
+    /// the next blocks; going down keeps positions below the cursor in place
+    fn continue_reevaluation() {
+        let mut cursor = Self::reevaluation_cursor();
+        if cursor == 0 {
+            return;
+        }
+        let mut checked = 0;
+        while cursor > 0 && checked < MAX_REEVALUATIONS_PER_BLOCK {
+            cursor -= 1;
+            checked += 1;
+            // executions may have removed proposals from the top
+            if cursor < Self::open_proposals_count() {
+                Self::execute_if_quorum(Self::open_proposal_at(cursor));
+            }
+        }
+        <ReevaluationCursor<T>>::put(cursor);
+    }
+
+    /// execute an open non-validator proposal which has quorum with the current set
+    fn execute_if_quorum(transfer_id: ProposalId) {
+        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
+        let amount = match transfer.kind {
+            Kind::Validator => return,
+            Kind::Transfer => <TransferMessages<T>>::get(transfer.message_id).amount,
+            _ => 0,
+        };
+        let weight = Self::decisive_weight(&transfer, Self::total_validator_weight());
+        if !transfer.open
+            || !Self::votes_are_enough(transfer.votes, transfer.signatures, weight, amount)
+        {
+            return;
+        }
+        // a failed execution keeps the proposal open, as in _sign
+        if Self::execute_proposal(&transfer).is_ok() {
+            transfer.locked = <BridgeTransfers<T>>::get(transfer_id).locked;
+            transfer.open = false;
+            <BridgeTransfers<T>>::insert(transfer_id, transfer);
+            Self::forget_open_proposal(transfer_id);
+        }
+    }
+
+    /// expire the proposal if it's past its deadline or can't reach quorum anymore,
+    /// otherwise execute it once it has quorum
+    fn reconcile_proposal(transfer_id: ProposalId) {
+        let transfer = <BridgeTransfers<T>>::get(transfer_id);
+        if !transfer.open {
+            return;
+        }
+        let now = <system::Module<T>>::block_number();
+        let past_deadline = Self::proposal_deadline(transfer_id).map_or(false, |d| d <= now);
+        if past_deadline || !Self::can_reach_quorum(&transfer) {
+            let _ = Self::expire_proposal(transfer_id);
+            return;
+        }
+        Self::execute_if_quorum(transfer_id);
+    }
+
+    /// quorum is still possible if every validator which hasn't signed yet does
+    fn can_reach_quorum(transfer: &BridgeTransfer<T::Hash>) -> bool {
+        let message = <TransferMessages<T>>::get(transfer.message_id);
+        let (authority, amount) = match transfer.kind {
+            // removal targets are handled in _sign, validator proposals expire by their ttl
+            Kind::Validator => return true,
+            Kind::Transfer if message.action == Status::Deposit => {
+                (Self::mint_validators(), message.amount)
+            }
+            Kind::Transfer => (Vec::new(), message.amount),
+            Kind::Bridge => (Self::pause_validators(), 0),
+            _ => (Vec::new(), 0),
+        };
+        let candidates = if authority.is_empty() {
+            Self::validator_accounts()
+        } else {
+            authority
+        };
+        let (votes, signatures) = candidates
+            .iter()
+            .filter(|v| <Validators<T>>::exists(*v) && !Self::is_shadow_validator(*v))
+            .filter(|v| !Self::has_signed(transfer.transfer_id, (*v).clone()))
+            .fold((transfer.votes, transfer.signatures), |(votes, signatures), v| {
+                (votes + MemberId::from(Self::voting_weight(v)), signatures + 1)
+            });
+        let weight = Self::decisive_weight(transfer, Self::total_validator_weight());
+        Self::votes_are_enough(votes, signatures, weight, amount)
+    }
+
+    ///ensure that such transfer exist
+    fn get_transfer_id_checked(transfer_hash: T::Hash, kind: Kind) -> Result {
+        if !<TransferId<T>>::exists(transfer_hash) {
+            Self::create_transfer(transfer_hash, kind)?;
+        }
+
+        Ok(())
+    }
+
+    fn pause_the_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
+        <BridgeIsOperational<T>>::mutate(|x| *x = false);
+        // votes given to resume before the pause must not count
+        Self::drop_bridge_proposal(("resume", Self::pause_epoch()).using_encoded(<T as system::Trait>::Hashing::hash));
+        Self::update_status(message.message_id, Status::Confirmed, Kind::Bridge)
+    }
+
+    fn resume_the_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
+        let next_epoch = Self::pause_epoch()
+            .checked_add(1)
+            .ok_or("Overflow incrementing pause epoch")?;
+        <BridgeIsOperational<T>>::mutate(|x| *x = true);
+        Self::drop_bridge_proposal(("pause", Self::pause_epoch()).using_encoded(<T as system::Trait>::Hashing::hash));
+        <PauseEpoch<T>>::put(next_epoch);
+        Self::update_status(message.message_id, Status::Confirmed, Kind::Bridge)
+    }
+
+    /// validator set to install is not empty, fits the maximum and has no duplicates
+    fn check_validator_set(accounts: &[T::AccountId]) -> Result {
+        ensure!(!accounts.is_empty(), "Validator set can't be empty");
+        ensure!(
+            accounts.len() <= Self::max_validators() as usize,
+            "Validators maximum reached."
+        );
+        let has_duplicates = accounts
+            .iter()
+            .enumerate()
+            .any(|(i, a)| accounts[..i].contains(a));
+        ensure!(!has_duplicates, "Duplicate validator in set");
+
+        Ok(())
+    }
+
+    fn replace_validator_set(accounts: Vec<T::AccountId>) -> Result {
+        Self::check_validator_set(&accounts)?;
+
+        for old in Self::validator_accounts() {
+            let weight = <ValidatorWeight<T>>::take(&old);
+            <Validators<T>>::remove(&old);
+            <ShadowValidators<T>>::remove(&old);
+            // kept validators keep their index and signatures, counted with the new weight
+            if !accounts.contains(&old) {
+                Self::release_validator_index(&old, weight);
+                Self::refund_validator_bond(&old);
+            } else if weight != 1 {
+                Self::reweigh_open_votes(&old, weight, Some(1));
+            }
+            <ValidatorLastActive<T>>::remove(&old);
+        }
+        let now = <system::Module<T>>::block_number();
+        let old_accounts = Self::validator_accounts();
+        let added = accounts.iter().filter(|a| !old_accounts.contains(a)).count() as u64;
+        <TotalValidatorsEverAdded<T>>::mutate(|x| *x = x.saturating_add(added));
+        for new in accounts.iter() {
+            <Validators<T>>::insert(new, true);
+            <ValidatorWeight<T>>::insert(new, 1);
+            Self::assign_validator_index(new);
+            <ValidatorLastActive<T>>::insert(new, now);
+        }
+        <ValidatorsCount<T>>::put(accounts.len() as u32);
+        <TotalValidatorWeight<T>>::put(accounts.len() as u32);
+        <ValidatorAccounts<T>>::put(accounts.clone());
+        Self::reevaluate_open_proposals();
+
+        Self::deposit_event(RawEvent::ValidatorSetReplaced(accounts));
+        Ok(())
+    }
+
+    /// record super admin vote, true once two thirds of admins agree
+    fn super_admin_vote(
+        admin: T::AccountId,
+        hash: T::Hash,
+    ) -> rstd::result::Result<bool, &'static str> {
+        let admins = Self::super_admins();
+        ensure!(admins.contains(&admin), "Only super admins can call this function");
+
+        let mut votes = Self::super_admin_votes(hash);
+        ensure!(!votes.contains(&admin), "This super admin has already voted");
+        votes.push(admin);
+
+        if votes.len() * 3 < admins.len() * 2 {
+            <SuperAdminVotes<T>>::insert(hash, votes);
+            return Ok(false);
+        }
+        // executed, votes of this epoch can't be reused
+        let next_epoch = Self::super_admin_epoch()
+            .checked_add(1)
+            .ok_or("Overflow incrementing super admin epoch")?;
+        <SuperAdminVotes<T>>::remove(hash);
+        <SuperAdminEpoch<T>>::put(next_epoch);
+        Ok(true)
+    }
+
+    /// close partially signed pause or resume proposal and forget it
+    fn drop_bridge_proposal(hash: T::Hash) {
+        if !<TransferId<T>>::exists(hash) {
+            return;
+        }
+        let transfer_id = <TransferId<T>>::get(hash);
+        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
+        if transfer.open {
+            transfer.open = false;
+            <BridgeTransfers<T>>::insert(transfer_id, transfer);
+            Self::forget_open_proposal(transfer_id);
+            <BridgeMessages<T>>::remove(hash);
+            <TransferId<T>>::remove(hash);
+        }
+    }
+
+    /// add fee to today's bucket, dropping buckets older than the retention window
+    fn record_fee(fee: TokenBalance) {
+        let today = Self::current_day_index();
+        let cutoff = today.saturating_sub(FEE_RETENTION_DAYS);
+        let mut days = Self::fee_days();
+        while days.first().map_or(false, |day| *day < cutoff) {
+            <DailyFees<T>>::remove(days.remove(0));
+        }
+        if days.last() != Some(&today) {
+            days.push(today);
+        }
+        <FeeDays<T>>::put(days);
+        <DailyFees<T>>::mutate(today, |x| *x = x.saturating_add(fee));
+    }
+
+    /// byte and mask of a validator index in SignatureBits
+    fn signature_bit(index: u32) -> (usize, u8) {
+        ((index / 8) as usize, 1 << (index % 8))
+    }
+
+    fn set_signature_bit(transfer_id: ProposalId, index: u32, signed: bool) {
+        let (byte, mask) = Self::signature_bit(index);
+        let mut bits = Self::signature_bits(transfer_id);
+        if bits.len() <= byte {
+            if !signed {
+                return;
+            }
+            bits.resize(byte + 1, 0);
+        }
+        if signed {
+            bits[byte] |= mask;
+        } else {
+            bits[byte] &= !mask;
+        }
+        <SignatureBits<T>>::insert(transfer_id, bits);
+    }
+
+    /// next unused index, an account keeps its index for good
+    fn assign_validator_index(validator: &T::AccountId) {
+        if <ValidatorIndex<T>>::exists(validator) {
+            return;
+        }
+        let index = Self::next_validator_index();
+        <NextValidatorIndex<T>>::put(index + 1);
+        <ValidatorIndex<T>>::insert(validator, index);
+        <IndexedValidators<T>>::insert(index, validator.clone());
+    }
+
+    /// take departing validator's votes off open proposals, its index is kept
+    fn release_validator_index(validator: &T::AccountId, weight: u32) {
+        if !<ValidatorIndex<T>>::exists(validator) {
+            return;
+        }
+        Self::reweigh_open_votes(validator, weight, None);
+    }
+
+    /// recount validator's vote on open proposals it signed with a new weight, None drops it
+    fn reweigh_open_votes(validator: &T::AccountId, old_weight: u32, new_weight: Option<u32>) {
+        let index = Self::validator_index(validator);
+        for transfer_id in Self::open_proposals() {
+            if !Self::has_signed(transfer_id, validator.clone()) {
+                continue;
+            }
+            <BridgeTransfers<T>>::mutate(transfer_id, |transfer| {
+                // the vote may have been counted with an older weight
+                transfer.votes = transfer
+                    .votes
+                    .checked_sub(MemberId::from(old_weight))
+                    .unwrap_or(0);
+                match new_weight {
+                    Some(weight) => {
+                        transfer.votes = transfer.votes.saturating_add(MemberId::from(weight))
+                    }
+                    None => transfer.signatures = transfer.signatures.checked_sub(1).unwrap_or(0),
+                }
+            });
+            if new_weight.is_none() {
+                Self::set_signature_bit(transfer_id, index, false);
+            }
+        }
+    }
+
+    /// weight counted in quorums, zero in shadow mode
+    fn voting_weight(validator: &T::AccountId) -> u32 {
+        if Self::is_shadow_validator(validator) {
+            0
+        } else {
+            <ValidatorWeight<T>>::get(validator)
+        }
+    }
+
+    /// log shadow validator's vote without counting it
+    fn record_shadow_vote(validator: T::AccountId, transfer_id: ProposalId) -> Result {
+        let mut votes = Self::shadow_votes(transfer_id);
+        ensure!(!votes.contains(&validator), "This validator has already signed");
+        votes.push(validator.clone());
+        <ShadowVotes<T>>::insert(transfer_id, votes);
+        Self::deposit_event(RawEvent::ShadowSigned(transfer_id, validator));
+        Ok(())
+    }
+
+    /// add validator
+    fn _add_validator(info: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
+        let delay = Self::validator_add_delay();
+        if delay == T::BlockNumber::sa(0) {
+            Self::activate_validator(&info.account)?;
+            return Self::update_status(info.message_id, Status::Confirmed, Kind::Validator);
+        }
+
+        // stays Approved until activate_pending_validators
+        ensure!(<ValidatorsCount<T>>::get() < Self::max_validators(), "Validators maximum reached.");
+        // a delay past the last block keeps the addition pending until it's vetoed
+        let activation = <system::Module<T>>::block_number().saturating_add(delay);
+        <PendingValidatorAdditions<T>>::mutate(|v| v.push((info.account, activation)));
+        Self::update_status(info.message_id, Status::Approved, Kind::Validator)
+    }
+
+    fn activate_validator(account: &T::AccountId) -> Result {
+        // the account may have joined through another path during the delay, counting
+        // it again would leave a second bond reserved
+        ensure!(!<Validators<T>>::exists(account), "Already a validator");
+        ensure!(<ValidatorsCount<T>>::get() < Self::max_validators(), "Validators maximum reached.");
+        let bond = Self::validator_bond();
+        if !bond.is_zero() {
+            <balances::Module<T>>::reserve(account, bond)
+                .map_err(|_| "Not enough balance for the validator bond")?;
+            <BondedAmount<T>>::insert(account, bond);
+        }
+        <Validators<T>>::insert(account, true);
+        <ValidatorsCount<T>>::mutate(|x| *x += 1);
+        <ValidatorWeight<T>>::insert(account, 1);
+        <TotalValidatorWeight<T>>::mutate(|x| *x += 1);
+        <TotalValidatorsEverAdded<T>>::mutate(|x| *x = x.saturating_add(1));
+        Self::assign_validator_index(account);
+        <ValidatorAccounts<T>>::mutate(|v| {
+            if !v.contains(account) {
+                v.push(account.clone())
+            }
+        });
+        <ValidatorLastActive<T>>::insert(account, <system::Module<T>>::block_number());
+        Self::clear_validator_proposal("remove", account);
+        Ok(())
+    }
+
+    fn refund_validator_bond(account: &T::AccountId) {
+        let bond = Self::bonded_amount(account);
+        if !bond.is_zero() {
+            let _ = <balances::Module<T>>::unreserve(account, bond);
+            <BondedAmount<T>>::remove(account);
+        }
+    }
+
+    fn slash_validator_bond(account: &T::AccountId) {
+        let bond = Self::bonded_amount(account);
+        if !bond.is_zero() {
+            let _ = <balances::Module<T>>::slash_reserved(account, bond);
+            <BondedAmount<T>>::remove(account);
+        }
+    }
+
+    /// forget finished proposal of the opposite action, so the account can go through it again
+    fn clear_validator_proposal(action: &str, account: &T::AccountId) {
+        let hash = (action, account).using_encoded(<T as system::Trait>::Hashing::hash);
+        <ValidatorHistory<T>>::remove(hash);
+        <TransferId<T>>::remove(hash);
+    }
+
+    /// activate additions whose veto window is over
+    fn activate_pending_validators(now: T::BlockNumber) {
+        let (due, waiting): (Vec<_>, Vec<_>) = Self::pending_validator_additions()
+            .into_iter()
+            .partition(|(_, activation)| *activation <= now);
+        if due.is_empty() {
+            return;
+        }
+        <PendingValidatorAdditions<T>>::put(waiting);
+        for (account, _) in due {
+            let hash = ("add", &account).using_encoded(<T as system::Trait>::Hashing::hash);
+            // the set may have filled up during the window
+            match Self::activate_validator(&account) {
+                Ok(()) => {
+                    let _ = Self::update_status(hash, Status::Confirmed, Kind::Validator);
+                }
+                Err(_) => {
+                    let _ = Self::update_status(hash, Status::Canceled, Kind::Validator);
+                    // failed addition mustn't block proposing the account again
+                    Self::clear_validator_proposal("add", &account);
+                }
+            }
+        }
+    }
+
+    /// drop vetoed addition, the account can be proposed again
+    fn _veto_validator(info: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
+        ensure!(
+            Self::pending_validator_additions()
+                .iter()
+                .any(|(account, _)| *account == info.account),
+            "Validator addition is not pending"
+        );
+        <PendingValidatorAdditions<T>>::mutate(|v| {
+            v.retain(|(account, _)| *account != info.account)
+        });
+        let add_hash = ("add", &info.account).using_encoded(<T as system::Trait>::Hashing::hash);
+        <ValidatorHistory<T>>::remove(add_hash);
+        <TransferId<T>>::remove(add_hash);
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Validator)
+    }
+
+    /// remove validator
+    fn _remove_validator(info: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
+        Self::deactivate_validator(&info.account)?;
+        // removal by vote is for cause, the bond isn't returned
+        Self::slash_validator_bond(&info.account);
+        <ValidatorHistory<T>>::remove(info.message_id);
+        <TransferId<T>>::remove(info.message_id);
+        Ok(())
+    }
+
+    fn deactivate_validator(account: &T::AccountId) -> Result {
+        ensure!(<ValidatorsCount<T>>::get() > MIN_VALIDATORS, "Can not remove last validator.");
+        <Validators<T>>::remove(account);
+        <ValidatorsCount<T>>::mutate(|x| *x -= 1);
+        let weight = Self::voting_weight(account);
+        let counted_weight = <ValidatorWeight<T>>::take(account);
+        <ShadowValidators<T>>::remove(account);
+        Self::release_validator_index(account, counted_weight);
+        <TotalValidatorWeight<T>>::mutate(|x| *x -= weight);
+        <ValidatorAccounts<T>>::mutate(|v| v.retain(|a| a != account));
+        <ValidatorMetadata<T>>::remove(account);
+        Self::clear_validator_proposal("add", account);
+        <ValidatorLastActive<T>>::remove(account);
+
+        let count = <ValidatorsCount<T>>::get();
+        if count <= MIN_VALIDATORS.saturating_add(Self::validator_set_low_margin()) {
+            Self::deposit_event(RawEvent::ValidatorSetLow(count));
+        }
+        Self::reevaluate_open_proposals();
+        Ok(())
+    }
+
+    /// set validators maximum
+    fn _set_max_validators(info: LimitMessage<T::AccountId, T::Hash>) -> Result {
+        ensure!(info.limit <= MAX_VALIDATORS, "Validators maximum is above the hard limit.");
+        ensure!(info.limit >= <ValidatorsCount<T>>::get(), "Validators maximum is below current validators count.");
+        <MaxValidators<T>>::put(info.limit);
+        <TransferId<T>>::remove(info.message_id);
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Limits)
+    }
+
+    /// close deposit proposal without minting
+    fn _cancel_mint(info: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        Self::check_mint_cancelable(info.transfer_hash)?;
+        Self::close_transfer(info.transfer_hash)?;
+        <FailedMints<T>>::mutate(|v| v.retain(|id| *id != info.transfer_hash));
+        <DeferredMints<T>>::mutate(|v| v.retain(|id| *id != info.transfer_hash));
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Cancel)
+    }
+
+    /// close any unfinished transfer
+    fn _force_close(info: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        Self::check_closable(info.transfer_hash)?;
+        Self::close_transfer(info.transfer_hash)?;
+        // closed mint mustn't stay queued for retry_mint
+        <FailedMints<T>>::mutate(|v| v.retain(|id| *id != info.transfer_hash));
+        <DeferredMints<T>>::mutate(|v| v.retain(|id| *id != info.transfer_hash));
+        <RetryCount<T>>::remove(info.transfer_hash);
+        Self::deposit_event(RawEvent::TransferForceClosed(info.transfer_hash));
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Cancel)
+    }
+
+    /// move withdraw back to Approved, funds stay locked until a new confirmation
+    fn _cancel_burn_confirmation(info: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        Self::check_burn_confirmation_in_progress(info.transfer_hash)?;
+        Self::reset_burn_confirmation(info.transfer_hash)?;
+
+        // allow to abort the next confirmation as well
+        <TransferId<T>>::remove(info.message_id);
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Cancel)
+    }
+
+    /// back to approved, next confirm_transfer starts a fresh confirmation proposal
+    fn reset_burn_confirmation(message_id: T::Hash) -> Result {
+        Self::close_confirmation(message_id);
+        <ConfirmationId<T>>::remove(message_id);
+        <EthUnlockTx<T>>::remove(message_id);
+        Self::set_withdraw_phase(message_id, WithdrawPhase::Approved);
+        Self::update_status(message_id, Status::Approved, Kind::Transfer)
+    }
+
+    /// withdraw was reopened by confirm_transfer and isn't burned yet
+    fn check_burn_confirmation_in_progress(message_id: T::Hash) -> Result {
+        ensure!(
+            <TransferMessages<T>>::exists(message_id),
+            "Transfer message does not exist"
+        );
+        let message = <TransferMessages<T>>::get(message_id);
+        ensure!(
+            message.phase == WithdrawPhase::Confirming,
+            "Burn confirmation is not in progress"
+        );
+
+        Ok(())
+    }
+
+    /// transfer exists and is neither cancelled nor executed
+    fn check_closable(message_id: T::Hash) -> Result {
+        ensure!(
+            <TransferMessages<T>>::exists(message_id),
+            "Transfer message does not exist"
+        );
+        let message = <TransferMessages<T>>::get(message_id);
+        ensure!(message.status != Status::Canceled, "Transfer is already cancelled");
+
+        let transfer = <BridgeTransfers<T>>::get(<TransferId<T>>::get(message_id));
+        let is_executed = message.status == Status::Confirmed && !transfer.open;
+        ensure!(!is_executed, "Transfer is already executed");
+
+        Ok(())
+    }
+
+    /// close transfer proposal and mark it cancelled, unlocking withdrawn funds
+    fn close_transfer(message_id: T::Hash) -> Result {
+        let mut message = <TransferMessages<T>>::get(message_id);
+        let transfer_id = <TransferId<T>>::get(message_id);
+        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
+
+        // gross amount (fee included) stays locked from approval until burn
+        let is_locked = message.action == Status::Withdraw
+            && (message.status == Status::Approved || message.status == Status::Confirmed);
+        if is_locked {
+            if Self::claimable_refunds_enabled() {
+                <ClaimableRefunds<T>>::mutate(&message.substrate_address, |x| {
+                    *x = x.saturating_add(message.amount)
+                });
+            } else {
+                <token::Module<T>>::unlock(&message.substrate_address, message.amount)?;
+            }
+            <PendingBurnTotal<T>>::mutate(|x| *x = x.saturating_sub(message.amount));
+        }
+        <LockedFees<T>>::remove(message_id);
+
+        transfer.locked = 0;
+        transfer.open = false;
+        message.status = Status::Canceled;
+        message.phase = WithdrawPhase::Canceled;
+        Self::forget_open_withdraw(&message.substrate_address, message_id);
+        Self::forget_open_proposal(transfer_id);
+        Self::close_confirmation(message_id);
+        <BridgeTransfers<T>>::insert(transfer_id, transfer);
+        Self::reindex_transfer(message_id, Some(Status::Canceled));
+        <TransferMessages<T>>::insert(message_id, message);
+        Ok(())
+    }
+
+    /// stop burn confirmation voting of a withdraw if it's running
+    fn close_confirmation(message_id: T::Hash) {
+        if let Some(confirmation_id) = Self::confirmation_id_by_hash(message_id) {
+            <BridgeTransfers<T>>::mutate(confirmation_id, |t| t.open = false);
+            Self::forget_open_proposal(confirmation_id);
+        }
+    }
+
+    /// cancel a reported transfer found fraudulent by the quorum and pay the reporter
+    fn _confirm_fraud(info: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        Self::check_closable(info.transfer_hash)?;
+        ensure!(
+            <FraudReports<T>>::exists(info.transfer_hash),
+            "Transfer is not reported"
+        );
+        Self::close_transfer(info.transfer_hash)?;
+        Self::pay_fraud_bounty(info.transfer_hash)?;
+        Self::update_status(info.message_id, Status::Confirmed, Kind::Cancel)
+    }
+
+    /// the same message can be pre-registered and dropped again later
+    fn _drop_preregistered_mint(info: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        ensure!(
+            <ExpectedMints<T>>::exists(info.transfer_hash),
+            "Mint is not pre-registered"
+        );
+        <ExpectedMints<T>>::remove(info.transfer_hash);
+        <CancelMessages<T>>::remove(info.message_id);
+        <TransferId<T>>::remove(info.message_id);
+        Ok(())
+    }
+
+    /// bounty is limited by the fee account's balance
+    fn pay_fraud_bounty(message_id: T::Hash) -> Result {
+        if let Some((reporter, _)) = Self::fraud_report(message_id) {
+            let fee_account = Self::fee_account();
+            let free = <token::Module<T>>::balance_of(&fee_account)
+                .saturating_sub(<token::Module<T>>::locked(&fee_account));
+            let bounty = Self::fraud_bounty().min(free);
+            if bounty > 0 {
+                <token::Module<T>>::make_transfer(fee_account, reporter.clone(), bounty)?;
+                Self::deposit_event(RawEvent::FraudBountyPaid(message_id, reporter, bounty));
+            }
+        }
+        Ok(())
+    }
+
+    /// only deposits which are not executed yet can be cancelled
+    fn check_mint_cancelable(message_id: T::Hash) -> Result {
+        ensure!(
+            <TransferMessages<T>>::exists(message_id),
+            "Transfer message does not exist"
+        );
+        let message = <TransferMessages<T>>::get(message_id);
+        ensure!(message.action == Status::Deposit, "Only mints can be cancelled");
+        ensure!(message.status != Status::Confirmed, "Mint is already executed");
+        ensure!(message.status != Status::Canceled, "Mint is already cancelled");
+
+        Ok(())
+    }
+
+    /// close validator proposal which didn't reach quorum in time and forget it
+    fn expire_validator_proposal(hash: T::Hash) {
+        if !<TransferId<T>>::exists(hash) {
+            return;
+        }
+        let transfer_id = <TransferId<T>>::get(hash);
+        let mut transfer = <BridgeTransfers<T>>::get(transfer_id);
+        let age = <system::Module<T>>::block_number() - Self::transfer_created_at(transfer_id);
+        if transfer.open && age >= Self::validator_proposal_ttl() {
+            transfer.open = false;
+            <BridgeTransfers<T>>::insert(transfer_id, transfer);
+            Self::forget_open_proposal(transfer_id);
+            <ValidatorHistory<T>>::remove(hash);
+            <TransferId<T>>::remove(hash);
+        }
+    }
+
+    /// auto-cancel open proposals whose deadline is reached
+    fn expire_proposals(now: T::BlockNumber) {
+        let mut queue = Self::deadline_queue();
+        let due = queue.iter().take_while(|(deadline, _)| *deadline <= now).count();
+        if due == 0 {
+            return;
+        }
+        for (_, transfer_id) in queue.drain(..due) {
+            if <BridgeTransfers<T>>::get(transfer_id).open {
+                let _ = Self::expire_proposal(transfer_id);
+            }
+        }
+        <DeadlineQueue<T>>::put(queue);
+    }
+
+    /// close the proposal and drop or cancel its message, so it can be proposed again
+    fn expire_proposal(transfer_id: ProposalId) -> Result {
+        let transfer = <BridgeTransfers<T>>::get(transfer_id);
+        let hash = transfer.message_id;
+        <BridgeTransfers<T>>::mutate(transfer_id, |t| t.open = false);
+        Self::forget_open_proposal(transfer_id);
+        Self::deposit_event(RawEvent::ProposalExpired(transfer_id));
+
+        match transfer.kind {
+            Kind::Transfer => match Self::confirmation_id_by_hash(hash) {
+                Some(id) if id == transfer_id => Self::reset_burn_confirmation(hash),
+                _ if Self::messages(hash).action == Status::Deposit => {
+                    Self::drop_deposit(hash);
+                    Ok(())
+                }
+                _ => Self::close_transfer(hash),
+            },
+            Kind::Validator => {
+                <ValidatorHistory<T>>::remove(hash);
+                <TransferId<T>>::remove(hash);
+                Ok(())
+            }
+            Kind::Bridge => {
+                <BridgeMessages<T>>::remove(hash);
+                <TransferId<T>>::remove(hash);
+                Ok(())
+            }
+            Kind::Limits => {
+                <LimitMessages<T>>::remove(hash);
+                <TransferId<T>>::remove(hash);
+                Ok(())
+            }
+            Kind::Cancel => {
+                <CancelMessages<T>>::remove(hash);
+                <TransferId<T>>::remove(hash);
+                Ok(())
+            }
+        }
+    }
+
+    /// forget an expired deposit, validators can propose the same lock again
+    fn drop_deposit(message_id: T::Hash) {
+        Self::reindex_transfer(message_id, None);
+        <TransferMessages<T>>::remove(message_id);
+        <TransferId<T>>::remove(message_id);
+        <MintRecipients<T>>::remove(message_id);
+        <MintLockTimestamp<T>>::remove(message_id);
+        <FraudReports<T>>::remove(message_id);
+    }
+
+    /// (re)place the proposal in the deadline queue keeping it ordered
+    fn schedule_deadline(transfer_id: ProposalId, deadline: T::BlockNumber) {
+        <DeadlineQueue<T>>::mutate(|queue| {
+            queue.retain(|(_, id)| *id != transfer_id);
+            let position = queue
+                .iter()
+                .position(|(d, _)| *d > deadline)
+                .unwrap_or_else(|| queue.len());
+            queue.insert(position, (deadline, transfer_id));
+        });
+        <ProposalDeadline<T>>::insert(transfer_id, deadline);
+    }
+
+    /// check votes validity
+    fn votes_are_enough(
+        votes: MemberId,
+        signatures: MemberId,
+        total_weight: u32,
+        amount: TokenBalance,
+    ) -> bool {
+        signatures >= Self::min_absolute_votes()
+            && votes >= Self::required_votes_for_amount(total_weight, amount)
+    }
+
+    /// quorum plus extra votes of the highest tier below amount, at most total_weight
+    fn required_votes_for_amount(total_weight: u32, amount: TokenBalance) -> MemberId {
+        let base = Self::required_votes_of(total_weight);
+        let extra = Self::amount_tiers()
+            .into_iter()
+            .filter(|(threshold, _)| amount > *threshold)
+            .last()
+            .map_or(0, |(_, extra)| extra);
+        base.saturating_add(extra).min(MemberId::from(total_weight))
+    }
+
+    /// smallest weight which is at least QUORUM_PERCENT of total_weight, rounded up,
+    /// e.g. 2 of 3 but 3 of 4
+    fn required_votes_of(total_weight: u32) -> MemberId {
+        let scaled = MemberId::from(total_weight) * MemberId::from(QUORUM_PERCENT);
+        (scaled + 99) / 100
+    }
+
+    /// withdraw to an ethereum address, emits RelayMessage
+    fn create_eth_withdraw(from: T::AccountId, to: H160, amount: TokenBalance) -> Result {
+        if let Err(e) = Self::check_withdraw(&from, to, amount) {
+            <RejectedWithdrawsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+            return Err(e);
+        }
+
+        let nonce = Self::withdraw_nonce(&from);
+        let next_nonce = nonce.checked_add(1).ok_or("Withdraw nonce overflow")?;
+        let transfer_hash = Self::compute_transfer_hash(&from, to, amount, nonce);
+
+        let message = TransferMessage {
+            message_id: transfer_hash,
+            eth_address: to,
+            substrate_address: from,
+            amount,
+            status: Status::Withdraw,
+            action: Status::Withdraw,
+            phase: WithdrawPhase::Requested,
+        };
+        Self::get_transfer_id_checked(transfer_hash, Kind::Transfer)?;
+        <WithdrawNonce<T>>::insert(&message.substrate_address, next_nonce);
+        Self::deposit_event(RawEvent::RelayMessage(transfer_hash));
+        Self::emit_index_hint(transfer_hash, amount);
+
+        Self::reindex_transfer(transfer_hash, Some(Status::Withdraw));
+        <OpenWithdraws<T>>::mutate(&message.substrate_address, |v| v.push(transfer_hash));
+        <TransferMessages<T>>::insert(transfer_hash, message);
+        Ok(())
+    }
+
+    /// validator's vote for a deposit
+    fn sign_mint(
+        validator: T::AccountId,
+        message_id: T::Hash,
+        from: H160,
+        to: T::AccountId,
+        amount: TokenBalance,
+    ) -> Result {
+        if let Err(e) = Self::check_mint(amount).and_then(|_| Self::check_mint_recipient(&to)) {
+            <RejectedMintsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+            return Err(e);
+        }
+
+        let expected = Self::expected_mint(message_id);
+        Self::insert_mint_message(message_id, from, to, amount)?;
+
+        let transfer_id = <TransferId<T>>::get(message_id);
+        if let Some((registrar, _, _)) = expected {
+            if registrar != validator && Self::validators(registrar.clone()) {
+                Self::_sign(registrar, transfer_id)?;
+                // registrar's vote alone may be enough
+                if !Self::transfers(transfer_id).open {
+                    return Ok(());
+                }
+            }
+        }
+        Self::_sign(validator, transfer_id)
+    }
+
+    /// lock timestamp matches earlier signers and isn't older than MaxMintAge
+    fn check_mint_age(message_id: T::Hash, lock_timestamp: u64) -> Result {
+        ensure!(
+            !<MintLockTimestamp<T>>::exists(message_id)
+                || Self::mint_lock_timestamp(message_id) == lock_timestamp,
+            "Lock timestamp mismatch"
+        );
+        let now: u64 = <timestamp::Module<T>>::now().as_();
+        let max_age = Self::max_mint_age();
+        ensure!(
+            max_age == 0 || now.saturating_sub(lock_timestamp) <= max_age,
+            "Mint message is too old"
+        );
+
+        Ok(())
+    }
+
+    /// ethereum address which signed keccak256 of the encoded (message_id, from, to, amount)
+    fn recover_eth_signer(
+        message_id: T::Hash,
+        from: H160,
+        to: &T::AccountId,
+        amount: TokenBalance,
+        signature: &EthSignature,
+    ) -> Option<H160> {
+        let payload = (message_id, from, to, amount).using_encoded(runtime_io::keccak_256);
+        let mut sig = [0u8; 65];
+        sig[0..32].copy_from_slice(&signature.0);
+        sig[32..64].copy_from_slice(&signature.1);
+        sig[64] = signature.2;
+
+        let public = runtime_io::secp256k1_ecdsa_recover(&sig, &payload).ok()?;
+        Some(H160::from_slice(&runtime_io::keccak_256(&public[..])[12..]))
+    }
+
+    /// record deposit message and its proposal, unless it's already known
+    fn insert_mint_message(
+        message_id: T::Hash,
+        from: H160,
+        to: T::AccountId,
+        amount: TokenBalance,
+    ) -> Result {
+        if !<TransferMessages<T>>::exists(message_id) {
+            Self::check_expected_mint(message_id, &to, amount)?;
+            let message = TransferMessage {
+                message_id,
+                eth_address: from,
+                substrate_address: to,
+                amount,
+                status: Status::Deposit,
+                action: Status::Deposit,
+                phase: WithdrawPhase::Requested,
+            };
+            Self::get_transfer_id_checked(message_id, Kind::Transfer)?;
+            Self::reindex_transfer(message_id, Some(Status::Deposit));
+            <TransferMessages<T>>::insert(message_id, message);
+            <ExpectedMints<T>>::remove(message_id);
+        }
+
+        Ok(())
+    }
+
+    /// new mint message agrees with its pre-registration, if there is one
+    fn check_expected_mint(message_id: T::Hash, to: &T::AccountId, amount: TokenBalance) -> Result {
+        if let Some((_, expected_to, expected_amount)) = Self::expected_mint(message_id) {
+            ensure!(
+                expected_to == *to && expected_amount == amount,
+                "Mint doesn't match pre-registration"
+            );
+        }
+        Ok(())
+    }
+
+    /// validator can sign the mint, a new message gets a fresh proposal
+    fn check_mint_vote(validator: &T::AccountId, message_id: T::Hash) -> Result {
+        if !<TransferId<T>>::exists(message_id) {
+            ensure!(
+                !Self::processed_message(message_id),
+                "Message already processed"
+            );
+            return Ok(());
+        }
+        let transfer_id = <TransferId<T>>::get(message_id);
+        ensure!(
+            <BridgeTransfers<T>>::get(transfer_id).open,
+            "This transfer is not open"
+        );
+        let signed = if Self::is_shadow_validator(validator) {
+            Self::shadow_votes(transfer_id).contains(validator)
+        } else {
+            Self::has_signed(transfer_id, validator.clone())
+        };
+        ensure!(!signed, "This validator has already signed");
+        Ok(())
+    }
+
+    /// withdraw request is allowed
+    fn check_withdraw(from: &T::AccountId, to: H160, amount: TokenBalance) -> Result {
+        Self::check_withdraw_amount(from, amount)?;
+        ensure!(
+            !Self::reserved_eth_address(to),
+            "Destination is a reserved bridge address"
+        );
+
+        Ok(())
+    }
+
+    /// withdraw checks which don't depend on destination
+    fn check_withdraw_amount(from: &T::AccountId, amount: TokenBalance) -> Result {
+        ensure!(!Self::withdraws_paused(), "Withdraws are paused");
+        ensure!(amount > 0, "Amount must be positive");
+        ensure!(amount > Self::withdraw_fee(), "Amount must exceed the withdraw fee");
+        ensure!(amount >= Self::min_transfer_amount(), "Amount is below the minimum");
+        ensure!(
+            !Self::withdraw_whitelist_enabled() || Self::withdraw_whitelist(from),
+            "Account not permitted to withdraw"
+        );
+        ensure!(Self::keeps_reserve_ratio(amount), "Withdraw would breach the reserve ratio");
+
+        Ok(())
+    }
+
+    /// supply left after pending burns and this withdraw covers MinReserveRatio of collateral
+    fn keeps_reserve_ratio(amount: TokenBalance) -> bool {
+        let ratio = Self::min_reserve_ratio();
+        if ratio == 0 {
+            return true;
+        }
+        let remaining = <token::Module<T>>::total_supply()
+            .saturating_sub(Self::pending_burn_total())
+            .saturating_sub(amount);
+        let required = u128::from(Self::reported_collateral()) * u128::from(ratio) / 100;
+        u128::from(remaining) >= required
+    }
+
+    /// mint request is allowed
+    fn check_mint(amount: TokenBalance) -> Result {
+        ensure!(!Self::deposits_paused(), "Deposits are paused");
+        ensure!(amount > 0, "Amount must be positive");
+        ensure!(amount > Self::mint_fee(), "Amount must exceed the mint fee");
+        ensure!(amount >= Self::min_transfer_amount(), "Amount is below the minimum");
+
+        Ok(())
+    }
+
+    /// see NoValidatorSelfMint
+    fn check_mint_recipient(to: &T::AccountId) -> Result {
+        let self_mint =
+            Self::no_validator_self_mint() && Self::bootstrapping() && Self::validators(to.clone());
+        ensure!(!self_mint, "Can not mint to a validator during bootstrap");
+        let reserved =
+            *to == T::AccountId::default() || Self::reserved_mint_recipients().contains(to);
+        ensure!(!reserved, "Cannot mint to reserved account");
+
+        Ok(())
+    }
+
+    /// whole tokens scaled by the token decimals
+    fn tokens_to_base_units(
+        tokens: TokenBalance,
+    ) -> rstd::result::Result<TokenBalance, &'static str> {
+        let decimals = u32::from(<token::Module<T>>::token_default().decimals);
+        TokenBalance::from(10u32)
+            .checked_pow(decimals)
+            .and_then(|unit| tokens.checked_mul(unit))
+            .ok_or("Overflow converting tokens to base units")
+    }
+
+    /// rich event for indexers next to the minimal one, numbered in emission order
+    fn emit_index_hint(message_id: T::Hash, amount: TokenBalance) {
+        if !Self::indexing_hints() {
+            return;
+        }
+        let sequence = Self::indexed_events_count();
+        <IndexedEventsCount<T>>::put(sequence.wrapping_add(1));
+        let transfer_id = <TransferId<T>>::get(message_id);
+        Self::deposit_event(RawEvent::TransferIndexed(
+            transfer_id,
+            message_id,
+            sequence,
+            amount,
+        ));
+    }
+
+    /// lock funds after set_transfer call, the amount is recorded on the proposal
+    fn lock_for_burn(message_id: T::Hash, account: T::AccountId, amount: TokenBalance) -> Result {
+        let free = <token::Module<T>>::balance_of(&account)
+            .saturating_sub(<token::Module<T>>::locked(&account));
+        ensure!(free >= amount, "Not enough free balance to lock");
+        <token::Module<T>>::lock(account, amount)?;
+        <BridgeTransfers<T>>::mutate(<TransferId<T>>::get(message_id), |t| t.locked = amount);
+
+        Ok(())
+    }
+
+    fn mint_fee_recipient() -> T::AccountId {
+        Self::mint_fee_account().unwrap_or_else(Self::fee_account)
+    }
+
+    fn burn_fee_recipient() -> T::AccountId {
+        Self::burn_fee_account().unwrap_or_else(Self::fee_account)
+    }
+
+    fn execute_burn(message_id: T::Hash) -> Result {
+        let message = <TransferMessages<T>>::get(message_id);
+        let from = message.substrate_address.clone();
+        let to = message.eth_address;
+        let fee = <LockedFees<T>>::get(message_id);
+        let net_amount = message
+            .amount
+            .checked_sub(fee)
+            .ok_or("Fee exceeds transfer amount")?;
+        let balance_before = <token::Module<T>>::balance_of(&from);
+
+        <token::Module<T>>::unlock(&from, message.amount)?;
+        <PendingBurnTotal<T>>::mutate(|x| *x = x.saturating_sub(message.amount));
+        <token::Module<T>>::_burn(from.clone(), net_amount)?;
+        if fee > 0 {
+            <token::Module<T>>::make_transfer(from.clone(), Self::burn_fee_recipient(), fee)?;
+            Self::record_fee(fee);
+        }
+        <LockedFees<T>>::remove(message_id);
+        <BridgeTransfers<T>>::mutate(<TransferId<T>>::get(message_id), |t| t.locked = 0);
+        Self::set_withdraw_phase(message_id, WithdrawPhase::Burned);
+        Self::forget_open_withdraw(&from, message_id);
+        T::OnBurn::on_burn(&message_id, &from, to, net_amount);
+
+        let eth_unlock_tx = Self::eth_unlock_tx(message_id);
+        Self::deposit_event(RawEvent::Burned(
+            message_id,
+            from,
+            to,
+            net_amount,
+            eth_unlock_tx,
+        ));
+        let balance_after = <token::Module<T>>::balance_of(&message.substrate_address);
+        Self::deposit_event(RawEvent::BurnedBalance(
+            message_id,
+            message.substrate_address,
+            balance_before,
+            balance_after,
+        ));
+        Self::emit_index_hint(message_id, net_amount);
+        Ok(())
+    }
+
+    fn execute_mint(message: &TransferMessage<T::AccountId, T::Hash>) -> Result {
+        let within_cap = <token::Module<T>>::total_supply()
+            .checked_add(message.amount)
+            .map_or(false, |supply| supply <= Self::max_total_supply());
+        ensure!(within_cap, "Mint would exceed the total supply cap");
+
+        let mut recipients = Self::mint_recipients(message.message_id);
+        if recipients.is_empty() {
+            recipients.push((message.substrate_address.clone(), message.amount));
+        }
+        // fee is taken from the first recipient's share
+        let fee = Self::mint_fee().min(recipients[0].1);
+        recipients[0].1 -= fee;
+        // storage isn't rolled back, so balances, locks and the fee credit are all
+        // checked before anything is minted and the writes below can't fail
+        let escrow = Self::mint_escrow_enabled();
+        let mut credits: Vec<(T::AccountId, TokenBalance)> = Vec::new();
+        let fee_credit = (Self::mint_fee_recipient(), fee);
+        for (to, amount) in recipients.iter().chain(rstd::iter::once(&fee_credit)) {
+            match credits.iter_mut().find(|(account, _)| account == to) {
+                Some((_, credit)) => {
+                    *credit = credit
+                        .checked_add(*amount)
+                        .ok_or("overflow adding to balance")?
+                }
+                None => credits.push((to.clone(), *amount)),
+            }
+        }
+        ensure!(
+            recipients.iter().all(|(_, amount)| *amount > 0),
+            "amount should be non-zero"
+        );
+        for (to, credit) in credits.iter() {
+            <token::Module<T>>::balance_of(to)
+                .checked_add(*credit)
+                .ok_or("overflow adding to balance")?;
+        }
+        if escrow {
+            for (to, _) in recipients.iter() {
+                let escrowed = recipients
+                    .iter()
+                    .filter(|(account, _)| account == to)
+                    .try_fold(<token::Module<T>>::locked(to), |locked, (_, amount)| {
+                        locked.checked_add(*amount)
+                    });
+                ensure!(escrowed.is_some(), "overflow while locking");
+            }
+        }
+
+        for (to, amount) in recipients.iter() {
+            let balance_before = <token::Module<T>>::balance_of(to);
+            <token::Module<T>>::_mint(to.clone(), *amount)?;
+            Self::deposit_event(RawEvent::MintedBalance(
+                message.message_id,
+                to.clone(),
+                balance_before,
+                <token::Module<T>>::balance_of(to),
+            ));
+            if escrow && *amount > 0 {
+                <token::Module<T>>::lock(to.clone(), *amount)?;
+                <EscrowedMints<T>>::mutate((message.message_id, to.clone()), |x| {
+                    *x = x.saturating_add(*amount)
+                });
+            }
+            T::OnMint::on_mint(&message.message_id, to, *amount);
+            <MintSources<T>>::mutate(to, |v| {
+                if !v.contains(&message.eth_address) {
+                    v.push(message.eth_address)
+                }
+            });
+        }
+        if fee > 0 {
+            <token::Module<T>>::_mint(Self::mint_fee_recipient(), fee)?;
+            Self::record_fee(fee);
+        }
+        if Self::track_eth_senders() {
+            <KnownEthSenders<T>>::mutate(|v| {
+                if !v.contains(&message.eth_address) {
+                    v.push(message.eth_address)
+                }
+            });
+        }
+        Self::deposit_event(RawEvent::Minted(message.message_id));
+        Self::emit_index_hint(message.message_id, message.amount);
+        Self::update_status(message.message_id, Status::Confirmed, Kind::Transfer)
+    }
+
+    /// give up a mint which kept failing on retry
+    fn abandon_mint(message_id: T::Hash) -> Result {
+        <FailedMints<T>>::mutate(|v| v.retain(|id| *id != message_id));
+        <DeferredMints<T>>::mutate(|v| v.retain(|id| *id != message_id));
+        <RetryCount<T>>::remove(message_id);
+        Self::update_status(message_id, Status::Canceled, Kind::Transfer)?;
+        Self::deposit_event(RawEvent::MintPermanentlyFailed(message_id));
+        Ok(())
+    }
+
+    /// every recipient of a split mint has to pass the condition
+    fn mint_condition_holds(message: &TransferMessage<T::AccountId, T::Hash>) -> bool {
+        let recipients = Self::mint_recipients(message.message_id);
+        if recipients.is_empty() {
+            return T::MintCondition::can_mint(
+                &message.message_id,
+                &message.substrate_address,
+                message.amount,
+            );
+        }
+        recipients
+            .iter()
+            .all(|(to, amount)| T::MintCondition::can_mint(&message.message_id, to, *amount))
+    }
+
+    fn execute_transfer(message: TransferMessage<T::AccountId, T::Hash>) -> Result {
+        match message.action {
+            Status::Deposit => match message.status {
+                Status::Approved => {
+                    // not a failure, the mint waits for the condition and retry_mint
+                    if !Self::mint_condition_holds(&message) {
+                        <DeferredMints<T>>::mutate(|v| v.push(message.message_id));
+                        Self::deposit_event(RawEvent::MintDeferred(message.message_id));
+                        return Self::update_status(
+                            message.message_id,
+                            Status::Approved,
+                            Kind::Transfer,
+                        );
+                    }
+                    // quorum is reached anyway, the mint waits for retry_mint
+                    if Self::execute_mint(&message).is_err() {
+                        <FailedMints<T>>::mutate(|v| v.push(message.message_id));
+                        <FailedExecutionsCount<T>>::mutate(|x| *x = x.saturating_add(1));
+                        Self::deposit_event(RawEvent::MintFailed(message.message_id));
+                        return Self::update_status(
+                            message.message_id,
+                            Status::Approved,
+                            Kind::Transfer,
+                        );
+                    }
+                    Ok(())
+                }
+                _ => Err("Tried to deposit with non-supported status"),
+            },
+            Status::Withdraw => match message.status {
+                Status::Confirmed => Self::execute_burn(message.message_id),
+                Status::Approved => {
+                    let to = message.eth_address;
+                    let from = message.substrate_address.clone();
+                    // fee is fixed at approval, ethereum side releases the net amount
+                    let fee = Self::withdraw_fee().min(message.amount);
+                    Self::lock_for_burn(message.message_id, from.clone(), message.amount)?;
+                    <PendingBurnTotal<T>>::mutate(|x| *x = x.saturating_add(message.amount));
+                    <LockedFees<T>>::insert(message.message_id, fee);
+                    match Self::transfer_destination(message.message_id) {
+                        Some((chain_id, address)) => {
+                            Self::deposit_event(RawEvent::ApprovedChainRelayMessage(
+                                message.message_id,
+                                from,
+                                chain_id,
+                                address,
+                                message.amount - fee,
+                            ))
+                        }
+                        None => Self::deposit_event(RawEvent::ApprovedRelayMessage(
+                            message.message_id,
+                            from,
+                            to,
+                            message.amount - fee,
+                        )),
+                    }
+                    Self::emit_index_hint(message.message_id, message.amount - fee);
+                    Self::set_withdraw_phase(message.message_id, WithdrawPhase::Approved);
+                    Self::update_status(message.message_id, Status::Approved, Kind::Transfer)
+                }
+                _ => Err("Tried to withdraw with non-supported status"),
+            },
+            _ => Err("Tried to execute transfer with non-supported status"),
+        }
+    }
+
+    fn manage_validator(message: ValidatorMessage<T::AccountId, T::Hash>) -> Result {
+        match message.action {
+            Status::AddValidator => match message.status {
+                Status::Approved => Self::_add_validator(message),
+                _ => Err("Tried to add validator with non-supported status"),
+            },
+            Status::RemoveValidator => match message.status {
+                Status::Approved => Self::_remove_validator(message),
+                _ => Err("Tried to remove validator with non-supported status"),
+            },
+            Status::VetoValidator => match message.status {
+                Status::Approved => Self::_veto_validator(message),
+                _ => Err("Tried to veto validator with non-supported status"),
+            },
+            _ => Err("Tried to manage validator with non-supported status"),
+        }
+    }
+
+    fn manage_bridge(message: BridgeMessage<T::AccountId, T::Hash>) -> Result {
+        match message.action {
+            Status::PauseTheBridge => match message.status {
+                Status::Approved => Self::pause_the_bridge(message),
+                _ => Err("Tried to pause the bridge with non-supported status"),
+            },
+            Status::ResumeTheBridge => match message.status {
+                Status::Approved => Self::resume_the_bridge(message),
+                _ => Err("Tried to resume the bridge with non-supported status"),
+            },
+            _ => Err("Tried to manage bridge with non-supported status"),
+        }
+    }
+
+    fn manage_limits(message: LimitMessage<T::AccountId, T::Hash>) -> Result {
+        match message.action {
+            Status::SetMaxValidators => match message.status {
+                Status::Approved => Self::_set_max_validators(message),
+                _ => Err("Tried to set validators maximum with non-supported status"),
+            },
+            _ => Err("Tried to manage limits with non-supported status"),
+        }
+    }
+
+    fn manage_cancel(message: CancelMessage<T::AccountId, T::Hash>) -> Result {
+        match message.action {
+            Status::CancelMint => match message.status {
+                Status::Approved => Self::_cancel_mint(message),
+                _ => Err("Tried to cancel mint with non-supported status"),
+            },
+            Status::ForceClose => match message.status {
+                Status::Approved => Self::_force_close(message),
+                _ => Err("Tried to force close with non-supported status"),
+            },
+            Status::CancelBurnConfirmation => match message.status {
+                Status::Approved => Self::_cancel_burn_confirmation(message),
+                _ => Err("Tried to cancel burn confirmation with non-supported status"),
+            },
+            Status::ConfirmFraud => match message.status {
+                Status::Approved => Self::_confirm_fraud(message),
+                _ => Err("Tried to confirm fraud with non-supported status"),
+            },
+            Status::DropPreregisteredMint => match message.status {
+                Status::Approved => Self::_drop_preregistered_mint(message),
+                _ => Err("Tried to drop pre-registration with non-supported status"),
+            },
+            _ => Err("Tried to cancel with non-supported status"),
+        }
+    }
+
+    fn create_transfer(transfer_hash: T::Hash, kind: Kind) -> Result {
+        ensure!(
+            !<TransferId<T>>::exists(transfer_hash),
+            "This transfer already open"
+        );
+        ensure!(
+            !Self::processed_message(transfer_hash),
+            "Message already processed"
+        );
+
+        let transfer_id = Self::new_proposal(transfer_hash, kind)?;
+        <TransferId<T>>::insert(transfer_hash, transfer_id);
+
+        Ok(())
+    }
+
+    /// open a proposal for the message, caller links it to the message hash
+    fn new_proposal(
+        transfer_hash: T::Hash,
+        kind: Kind,
+    ) -> rstd::result::Result<ProposalId, &'static str> {
+        let transfer_id = <BridgeTransfersCount<T>>::get();
+        let bridge_transfers_count = <BridgeTransfersCount<T>>::get();
+        let new_bridge_transfers_count = bridge_transfers_count
+            .checked_add(1)
+            .ok_or("Overflow adding a new bridge transfer")?;
+
+        let transfer = BridgeTransfer {
+            transfer_id,
+            message_id: transfer_hash,
+            open: true,
+            votes: 0,
+            signatures: 0,
+            kind,
+            locked: 0,
+        };
+
+        <BridgeTransfers<T>>::insert(transfer_id, transfer);
+        <BridgeTransfersCount<T>>::mutate(|count| *count = new_bridge_transfers_count);
+        <MessageId<T>>::insert(transfer_id, transfer_hash);
+        let now = <system::Module<T>>::block_number();
+        <TransferCreatedAt<T>>::insert(transfer_id, now);
+        Self::remember_open_proposal(transfer_id);
+        // one ttl for every proposal: a deadline picked at creation would let the first
+        // voter decide how long the others get
+        let ttl = Self::proposal_ttl();
+        if ttl != T::BlockNumber::sa(0) {
+            // a ttl past the last block means no deadline
+            if let Some(deadline) = now.checked_add(&ttl) {
+                Self::schedule_deadline(transfer_id, deadline);
+            }
+        }
+
+        Ok(transfer_id)
+    }
+
+    fn update_status(id: T::Hash, status: Status, kind: Kind) -> Result {
+        match kind {
+            Kind::Transfer => {
+                let mut message = <TransferMessages<T>>::get(id);
+                Self::reindex_transfer(id, Some(status.clone()));
+                message.status = status;
+                <TransferMessages<T>>::insert(id, message);
+            }
+            Kind::Validator => {
+                let mut message = <ValidatorHistory<T>>::get(id);
+                message.status = status;
+                <ValidatorHistory<T>>::insert(id, message);
+            }
+            Kind::Bridge => {
+                let mut message = <BridgeMessages<T>>::get(id);
+                message.status = status;
+                <BridgeMessages<T>>::insert(id, message);
+            }
+            Kind::Limits => {
+                let mut message = <LimitMessages<T>>::get(id);
+                message.status = status;
+                <LimitMessages<T>>::insert(id, message);
+            }
+            Kind::Cancel => {
+                let mut message = <CancelMessages<T>>::get(id);
+                message.status = status;
+                <CancelMessages<T>>::insert(id, message);
+            }
+        }
+        Ok(())
+    }
+    fn is_approved(message_id: T::Hash) -> bool {
+        let status = <TransferMessages<T>>::get(message_id).status;
+        status == Status::Approved || status == Status::Confirmed
+    }
+    fn _confirm(validator: T::AccountId, message_id: T::Hash, eth_unlock_tx: H256) -> Result {
+        Self::check_confirmable(message_id)?;
+        // first confirmation fixes the tx hash, the rest must agree
+        ensure!(
+            !<EthUnlockTx<T>>::exists(message_id)
+                || Self::eth_unlock_tx(message_id) == eth_unlock_tx,
+            "Unlock tx hash mismatch"
+        );
+        // confirmation is a new round of votes under its own id
+        let id = match Self::confirmation_id_by_hash(message_id) {
+            Some(id) => {
+                // storage isn't rolled back, so a vote _sign would refuse is refused here
+                // before the writes below
+                ensure!(Self::transfers(id).open, "This transfer is not open");
+                ensure!(
+                    !Self::has_signed(id, validator.clone()),
+                    "This validator has already signed"
+                );
+                id
+            }
+            None => {
+                let id = Self::new_proposal(message_id, Kind::Transfer)?;
+                <ConfirmationId<T>>::insert(message_id, id);
+                id
+            }
+        };
+        <EthUnlockTx<T>>::insert(message_id, eth_unlock_tx);
+
+        Self::update_status(message_id, Status::Confirmed, Kind::Transfer)?;
+        Self::set_withdraw_phase(message_id, WithdrawPhase::Confirming);
+        Self::_sign(validator, id)
+    }
+    /// burn can be confirmed only after approval and before it's burned or cancelled
+    fn check_confirmable(message_id: T::Hash) -> Result {
+        let message = <TransferMessages<T>>::get(message_id);
+        ensure!(message.action == Status::Withdraw, "Only withdraws can be confirmed");
+        match message.phase {
+            WithdrawPhase::Approved | WithdrawPhase::Confirming => Ok(()),
+            WithdrawPhase::Requested => Err("This transfer must be approved first."),
+            WithdrawPhase::Burned => Err("Transfer is already burned"),
+            WithdrawPhase::Canceled => Err("Transfer is already cancelled"),
+        }
+    }
+    /// drop a burned or cancelled withdraw from the account's index, deposits aren't listed
+    fn forget_open_withdraw(account: &T::AccountId, message_id: T::Hash) {
+        let mut ids = Self::open_withdraws(account);
+        ids.retain(|id| *id != message_id);
+        if ids.is_empty() {
+            <OpenWithdraws<T>>::remove(account);
+        } else {
+            <OpenWithdraws<T>>::insert(account, ids);
+        }
+    }
+    /// every confirmation vote sets Confirming again, only the first one is a change
+    fn set_withdraw_phase(message_id: T::Hash, phase: WithdrawPhase) {
+        if Self::messages(message_id).phase == phase {
+            return;
+        }
+        Self::record_event(EventSummary::PhaseChanged(message_id, phase.clone()));
+        <TransferMessages<T>>::mutate(message_id, |message| message.phase = phase);
+    }
+    /// append to RecentEvents dropping entries out of the retention window or over the bound
+    fn record_event(summary: EventSummary<T::Hash>) {
+        let now = <system::Module<T>>::block_number();
+        let retention = Self::recent_events_retention();
+        <RecentEvents<T>>::mutate(|events| {
+            events.retain(|(block, _)| block.saturating_add(retention) >= now);
+            if events.len() >= MAX_RECENT_EVENTS {
+                events.remove(0);
+            }
+            events.push((now, summary));
+        });
+    }
+    /// closed deposit or withdraw which was executed or cancelled
+    fn is_finalized_transfer(transfer_id: ProposalId) -> bool {
+        let transfer = <BridgeTransfers<T>>::get(transfer_id);
+        let message = <TransferMessages<T>>::get(transfer.message_id);
+        let is_transfer = match transfer.kind {
+            Kind::Transfer => true,
+            _ => false,
+        };
+        // confirmation proposal goes together with the approval one
+        let is_approval = <TransferId<T>>::get(transfer.message_id) == transfer_id;
+        // withdraws are Confirmed from the start of the confirmation round until the burn
+        let is_done = match message.action {
+            Status::Deposit => {
+                message.status == Status::Confirmed || message.status == Status::Canceled
+            }
+            _ => {
+                message.phase == WithdrawPhase::Burned || message.phase == WithdrawPhase::Canceled
+            }
+        };
+        let is_confirming = Self::confirmation_id_by_hash(transfer.message_id)
+            .map_or(false, |id| <BridgeTransfers<T>>::get(id).open);
+        is_transfer && is_approval && !transfer.open && is_done && !is_confirming
+    }
+    /// approval proposal of a transfer which isn't pruned yet
+    fn is_prunable_later(transfer_id: ProposalId) -> bool {
+        if !<BridgeTransfers<T>>::exists(transfer_id) {
+            return false;
+        }
+        let transfer = <BridgeTransfers<T>>::get(transfer_id);
+        let is_transfer = match transfer.kind {
+            Kind::Transfer => true,
+            _ => false,
+        };
+        is_transfer
+            && <TransferId<T>>::exists(transfer.message_id)
+            && <TransferId<T>>::get(transfer.message_id) == transfer_id
+    }
+    /// drop transfer records, keeping its message id as processed
+    fn prune_transfer(transfer_id: ProposalId) {
+        let message_id = <MessageId<T>>::get(transfer_id);
+        let confirmation_id = Self::confirmation_id_by_hash(message_id);
+        for id in rstd::iter::once(transfer_id).chain(confirmation_id) {
+            <SignatureBits<T>>::remove(id);
+            <BridgeTransfers<T>>::remove(id);
+            <MessageId<T>>::remove(id);
+            <TransferCreatedAt<T>>::remove(id);
+            <ProposalDeadline<T>>::remove(id);
+        }
+        <TransferId<T>>::remove(message_id);
+        <ConfirmationId<T>>::remove(message_id);
+        Self::reindex_transfer(message_id, None);
+        <TransferMessages<T>>::remove(message_id);
+        <EthUnlockTx<T>>::remove(message_id);
+        <MintRecipients<T>>::remove(message_id);
+        <MintLockTimestamp<T>>::remove(message_id);
+        <RetryCount<T>>::remove(message_id);
+        <FraudReports<T>>::remove(message_id);
+        <TransferDestinations<T>>::remove(message_id);
+        <ProcessedMessages<T>>::insert(message_id, true);
+    }
+    /// move transfer message id to the bucket of its new status
+    fn reindex_transfer(message_id: T::Hash, status: Option<Status>) {
+        if let Some(position) = <TransferStatusPosition<T>>::take(message_id) {
+            // swap with the last id so the bucket has no gaps
+            let old = <TransferMessages<T>>::get(message_id).status;
+            let last = Self::transfers_with_status_count(&old).saturating_sub(1);
+            let moved = <TransfersByStatus<T>>::take((old.clone(), last));
+            if position != last {
+                <TransfersByStatus<T>>::insert((old.clone(), position), moved);
+                <TransferStatusPosition<T>>::insert(moved, position);
+            }
+            <TransfersByStatusCount<T>>::insert(old, last);
+        }
+        if let Some(status) = status {
+            Self::record_event(EventSummary::StatusChanged(message_id, status.clone()));
+            let position = Self::transfers_with_status_count(&status);
+            <TransfersByStatus<T>>::insert((status.clone(), position), message_id);
+            <TransfersByStatusCount<T>>::insert(status, position + 1);
+            <TransferStatusPosition<T>>::insert(message_id, position);
+        }
+    }
+    fn remember_open_proposal(transfer_id: ProposalId) {
+        let position = Self::open_proposals_count();
+        <OpenProposals<T>>::insert(position, transfer_id);
+        <OpenProposalPosition<T>>::insert(transfer_id, position);
+        <OpenProposalsCount<T>>::put(position + 1);
+    }
+    fn forget_open_proposal(transfer_id: ProposalId) {
+        if let Some(position) = <OpenProposalPosition<T>>::take(transfer_id) {
+            // swap with the last id so positions have no gaps
+            let last = Self::open_proposals_count().saturating_sub(1);
+            let moved = <OpenProposals<T>>::take(last);
+            if position != last {
+                <OpenProposals<T>>::insert(position, moved);
+                <OpenProposalPosition<T>>::insert(moved, position);
+            }
+            <OpenProposalsCount<T>>::put(last);
+        }
+    }
+    fn check_validator(validator: T::AccountId) -> Result {
+        let is_trusted = <Validators<T>>::exists(validator);
+        ensure!(is_trusted, "Only validators can call this function");
+
+        Ok(())
+    }
+    fn check_mint_validator(validator: T::AccountId) -> Result {
+        Self::check_validator(validator.clone())?;
+        let authority = Self::mint_validators();
+        ensure!(
+            authority.is_empty() || authority.contains(&validator),
+            "Only mint validators can call this function"
+        );
+
+        Ok(())
+    }
+    fn check_pause_validator(validator: T::AccountId) -> Result {
+        Self::check_validator(validator.clone())?;
+        let authority = Self::pause_validators();
+        ensure!(
+            authority.is_empty() || authority.contains(&validator),
+            "Only pause validators can call this function"
+        );
+
+        Ok(())
+    }
+    fn check_authority_set(validators: &[T::AccountId]) -> Result {
+        ensure!(
+            validators.iter().all(|v| <Validators<T>>::exists(v)),
+            "Authority members must be validators"
+        );
+
+        Ok(())
+    }
+    /// weight the quorum of the proposal is taken from, mints and pauses may have their own set
+    fn decisive_weight(transfer: &BridgeTransfer<T::Hash>, total_weight: u32) -> u32 {
+        let authority = match transfer.kind {
+            Kind::Transfer => {
+                let message = <TransferMessages<T>>::get(transfer.message_id);
+                if message.action != Status::Deposit {
+                    return total_weight;
+                }
+                Self::mint_validators()
+            }
+            Kind::Bridge => Self::pause_validators(),
+            _ => return total_weight,
+        };
+        if authority.is_empty() {
+            return total_weight;
+        }
+        authority.iter().map(Self::voting_weight).sum()
+    }
+}
+
+/// tests for this module
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use primitives::{Blake2Hasher, H160, H256};
+    use runtime_io::with_externalities;
+    use std::cell::RefCell;
+    use runtime_primitives::{
+        testing::{Digest, DigestItem, Header},
+        traits::{BlakeTwo256, IdentityLookup, OnFinalize, OnInitialize},
+        BuildStorage,
+    };
+    use support::{assert_err, assert_noop, assert_ok, impl_outer_event, impl_outer_origin};
+
+    impl_outer_origin! {
+        pub enum Origin for Test {}
+    }
+
+    mod bridge {
+        pub use crate::bridge::Event;
+    }
+
+    impl_outer_event! {
+        pub enum TestEvent for Test {
+            balances<T>, token<T>, bridge<T>,
+        }
+    }
+
+    // For testing the module, we construct most of a mock runtime. This means
+    // first constructing a configuration type (`Test`) which `impl`s each of the
+    // configuration traits of modules we want to use.
+    #[derive(Clone, Eq, PartialEq)]
+    pub struct Test;
+    impl system::Trait for Test {
+        type Origin = Origin;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type Digest = Digest;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type Event = TestEvent;
+        type Log = DigestItem;
+    }
+    impl balances::Trait for Test {
+        type Balance = u128;
+        type OnFreeBalanceZero = ();
+        type OnNewAccount = ();
+        type TransactionPayment = ();
+        type TransferPayment = ();
+        type DustRemoval = ();
+        type Event = TestEvent;
+    }
+    impl timestamp::Trait for Test {
+        type Moment = u64;
+        type OnTimestampSet = ();
+    }
+    impl token::Trait for Test {
+        type Event = TestEvent;
+    }
+    thread_local! {
+        static MINTED: RefCell<Vec<(u64, TokenBalance)>> = RefCell::new(vec![]);
+        static BURNED: RefCell<Vec<(u64, TokenBalance)>> = RefCell::new(vec![]);
+        static MINT_ALLOWED: RefCell<bool> = RefCell::new(true);
+    }
+
+    // records hook invocations of the current test thread
+    pub struct RecordHooks;
+    impl OnMintHandler<u64, H256> for RecordHooks {
+        fn on_mint(_: &H256, to: &u64, amount: TokenBalance) {
+            MINTED.with(|v| v.borrow_mut().push((*to, amount)));
+        }
+    }
+    impl OnBurnHandler<u64, H256> for RecordHooks {
+        fn on_burn(_: &H256, from: &u64, _: H160, amount: TokenBalance) {
+            BURNED.with(|v| v.borrow_mut().push((*from, amount)));
+        }
+    }
+
+    impl MintCondition<u64, H256> for RecordHooks {
+        fn can_mint(_: &H256, _: &u64, _: TokenBalance) -> bool {
+            MINT_ALLOWED.with(|v| *v.borrow())
+        }
+    }
+
+    impl Trait for Test {
+        type Event = TestEvent;
+        type OnMint = RecordHooks;
+        type OnBurn = RecordHooks;
+        type MintCondition = RecordHooks;
+    }
+
+    type BridgeModule = Module<Test>;
+    type TokenModule = token::Module<Test>;
+
+    const ETH_MESSAGE_ID: &[u8; 32] = b"0x5617efe391571b5dc8230db92ba65b";
+    const ETH_ADDRESS: &[u8; 20] = b"0x00b46c2526ebb8f4c9";
+    const ETH_UNLOCK_TX: &[u8; 32] = b"0x9b1d3f6e21a4c07d5e83fa62bc0e1d";
+    const V1: u64 = 1;
+    const V2: u64 = 2;
+    const V3: u64 = 3;
+    const V4: u64 = 4;
+    const USER1: u64 = 4;
+    const USER2: u64 = 5;
+    const FEE_ACCOUNT: u64 = 6;
+    const MINT_FEE_ACCOUNT: u64 = 7;
+    const BURN_FEE_ACCOUNT: u64 = 8;
+    const SA1: u64 = 11;
+    const SA2: u64 = 12;
+    const SA3: u64 = 13;
+    const OPERATOR: u64 = 14;
+
+    fn bridge_events() -> Vec<Event<Test>> {
+        system::Module::<Test>::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                TestEvent::bridge(event) => Some(event),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // This function basically just builds a genesis storage key/value store according to
+    // our desired mockup.
+    fn new_test_ext() -> runtime_io::TestExternalities<Blake2Hasher> {
+        let mut r = system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .0;
+
+        r.extend(
+            balances::GenesisConfig::<Test> {
+                balances: vec![
+                    (V1, 100000),
+                    (V2, 100000),
+                    (V3, 100000),
+                    (USER1, 100000),
+                    (USER2, 300000),
+                ],
+                vesting: vec![],
+                transaction_base_fee: 0,
+                transaction_byte_fee: 0,
+                existential_deposit: 500,
+                transfer_fee: 0,
+                creation_fee: 0,
+            }
+            .build_storage()
+            .unwrap()
+            .0,
+        );
+
+        r.extend(
+            GenesisConfig::<Test> {
+                validator_accounts: vec![V1, V2, V3],
+                super_admins: vec![SA1, SA2, SA3],
+                operator: OPERATOR,
+            }
+            .build_storage()
+            .unwrap()
+            .0,
+        );
+
+        r.into()
+    }
+
+    #[test]
+    fn token_eth2sub_mint_works() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            //substrate <----- ETH
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            let mut message = BridgeModule::messages(message_id);
+            assert_eq!(message.status, Status::Pending);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            message = BridgeModule::messages(message_id);
+            assert_eq!(message.status, Status::Confirmed);
+
+            let transfer = BridgeModule::transfers(0);
+            assert_eq!(transfer.open, false);
+
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::total_supply(), 1000);
+        })
+    }
+    #[test]
+    fn token_eth2sub_closed_transfer_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            //substrate <----- ETH
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V3),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "This transfer is not open"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::total_supply(), 1000);
+            let transfer = BridgeModule::transfers(0);
+            assert_eq!(transfer.open, false);
+
+            let message = BridgeModule::messages(message_id);
+            assert_eq!(message.status, Status::Confirmed);
+        })
+    }
+
+    #[test]
+    fn token_sub2eth_burn_works() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            //substrate <----- ETH
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+
+            //substrate ----> ETH
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            //RelayMessage(message_id) event emitted
+
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            let get_message = || BridgeModule::messages(sub_message_id);
+
+            let mut message = get_message();
+            assert_eq!(message.status, Status::Withdraw);
+
+            //approval
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+
+            message = get_message();
+            assert_eq!(message.status, Status::Approved);
+
+            // at this point transfer is in Approved status and are waiting for confirmation
+            // from ethereum side to burn. Funds are locked.
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            // once it happends, validators call confirm_transfer
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+
+            message = get_message();
+            let transfer = BridgeModule::transfers(2);
+            assert_eq!(message.status, Status::Confirmed);
+            assert_eq!(transfer.open, true);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            // assert_ok!(BridgeModule::confirm_transfer(Origin::signed(USER1), sub_message_id));
+            //Burned(Hash, AccountId, H160, u64) event emitted
+
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+            assert_eq!(TokenModule::total_supply(), 500);
+        })
+    }
+    #[test]
+    fn duplicate_confirmation_should_not_change_anything() {
+        with_externalities(&mut new_test_ext(), || {
+            let sub_message_id = approved_withdraw_for_refund();
+            let eth_unlock_tx = H256::from(ETH_UNLOCK_TX);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+
+            assert_noop!(
+                BridgeModule::confirm_transfer(Origin::signed(V1), sub_message_id, eth_unlock_tx),
+                "This validator has already signed"
+            );
+            assert_noop!(
+                BridgeModule::confirm_transfer(
+                    Origin::signed(V2),
+                    sub_message_id,
+                    H256::from(ETH_MESSAGE_ID)
+                ),
+                "Unlock tx hash mismatch"
+            );
+            assert_eq!(BridgeModule::eth_unlock_tx(sub_message_id), eth_unlock_tx);
+            assert_eq!(
+                BridgeModule::messages(sub_message_id).phase,
+                WithdrawPhase::Confirming
+            );
+            let phase_changes = || {
+                <RecentEvents<Test>>::get()
+                    .into_iter()
+                    .filter(|(_, summary)| match summary {
+                        EventSummary::PhaseChanged(..) => true,
+                        _ => false,
+                    })
+                    .count()
+            };
+            let before = phase_changes();
+            BridgeModule::set_withdraw_phase(sub_message_id, WithdrawPhase::Confirming);
+            assert_eq!(phase_changes(), before);
+        })
+    }
+    #[test]
+    fn token_sub2eth_burn_fail_skip_approval() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            //substrate <----- ETH
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::total_supply(), 1000);
+
+            //substrate ----> ETH
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            //RelayMessage(message_id) event emitted
+
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            let message = BridgeModule::messages(sub_message_id);
+            assert_eq!(message.status, Status::Withdraw);
+
+            assert_eq!(TokenModule::locked(USER2), 0);
+            // lets say validators blacked out and we
+            // try to confirm without approval anyway
+            assert_noop!(
+                BridgeModule::confirm_transfer(
+                    Origin::signed(V1),
+                    sub_message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ),
+                "This transfer must be approved first."
+            );
+        })
+    }
+    #[test]
+    fn add_validator_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            let id = BridgeModule::message_id_by_transfer_id(0);
+            let mut message = BridgeModule::validator_history(id);
+            assert_eq!(message.status, Status::Pending);
+
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            message = BridgeModule::validator_history(id);
+            assert_eq!(message.status, Status::Confirmed);
+            assert_eq!(BridgeModule::validators_count(), 4);
+        })
+    }
+    #[test]
+    fn remove_validator_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            let id = BridgeModule::message_id_by_transfer_id(0);
+            let mut message = BridgeModule::validator_history(id);
+            assert_eq!(message.status, Status::Pending);
+
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            message = BridgeModule::validator_history(id);
+            assert_eq!(message.status, Status::Revoked);
+            assert_eq!(BridgeModule::validators_count(), 2);
+        })
+    }
+    #[test]
+    fn remove_last_validator_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_eq!(BridgeModule::validators_count(), 2);
+
+            // the removed validator isn't counted, so the other one decides alone
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V2));
+
+            assert_eq!(BridgeModule::validators_count(), 1);
+            // TODO: fails through different hashes
+            // assert_ok fails with corect error but the noop below fails with different hashes
+            // assert_noop!(BridgeModule::remove_validator(Origin::signed(V1), V1), "Cant remove last validator");
+        })
+    }
+    #[test]
+    fn pause_the_bridge_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+
+            assert_eq!(BridgeModule::bridge_transfers_count(), 1);
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            let id = BridgeModule::message_id_by_transfer_id(0);
+            let mut message = BridgeModule::bridge_messages(id);
+            assert_eq!(message.status, Status::Pending);
+
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            message = BridgeModule::bridge_messages(id);
+            assert_eq!(message.status, Status::Confirmed);
+        })
+    }
+    #[test]
+    fn extrinsics_restricted_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+
+            // substrate <-- Ethereum
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Bridge is not operational"
+            );
+        })
+    }
+    #[test]
+    fn double_pause_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_noop!(BridgeModule::pause_bridge(Origin::signed(V1)), "Bridge is not operational already");
+        })
+    }
+    #[test]
+    fn pause_and_resume_the_bridge_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V1)));
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+        })
+    }
+    #[test]
+    fn pause_resume_and_pause_again_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_eq!(BridgeModule::pause_epoch(), 0);
+
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V1)));
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            assert_eq!(BridgeModule::pause_epoch(), 1);
+
+            // second pause gets a fresh proposal instead of the closed one
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_transfers_count(), 3);
+            let id = BridgeModule::message_id_by_transfer_id(2);
+            assert_eq!(BridgeModule::bridge_messages(id).status, Status::Pending);
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_eq!(BridgeModule::bridge_messages(id).status, Status::Confirmed);
+
+            let first_id = BridgeModule::message_id_by_transfer_id(0);
+            assert_ne!(first_id, id);
+            assert_eq!(BridgeModule::transfers(0).open, false);
+        })
+    }
+    #[test]
+    fn multiple_pause_resume_cycles_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            for epoch in 0..3 {
+                assert_eq!(BridgeModule::pause_epoch(), epoch);
+                assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+                assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+                assert_eq!(BridgeModule::bridge_is_operational(), false);
+
+                // resume needs a fresh quorum every cycle
+                assert_ok!(BridgeModule::resume_bridge(Origin::signed(V1)));
+                assert_eq!(BridgeModule::bridge_is_operational(), false);
+                let resume_id = BridgeModule::message_id_by_transfer_id(epoch * 2 + 1);
+                assert_eq!(BridgeModule::bridge_messages(resume_id).status, Status::Pending);
+
+                assert_ok!(BridgeModule::resume_bridge(Origin::signed(V2)));
+                assert_eq!(BridgeModule::bridge_is_operational(), true);
+                assert_eq!(BridgeModule::bridge_messages(resume_id).status, Status::Confirmed);
+            }
+            assert_eq!(BridgeModule::bridge_transfers_count(), 6);
+        })
+    }
+    #[test]
+    fn next_proposal_id_should_match_created_proposal() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_eq!(BridgeModule::next_proposal_id(), 0);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::transfer_id_by_hash(message_id), 0);
+
+            let predicted = BridgeModule::next_proposal_id();
+            assert_eq!(predicted, 1);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            let hash = BridgeModule::message_id_by_transfer_id(predicted);
+            assert_eq!(BridgeModule::transfer_id_by_hash(hash), predicted);
+            assert_eq!(BridgeModule::transfers(predicted).transfer_id, predicted);
+            assert_eq!(BridgeModule::next_proposal_id(), 2);
+        })
+    }
+    #[test]
+    fn lowered_max_validators_should_reject_new_validators() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::max_validators(), MAX_VALIDATORS);
+
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V1), 3));
+            assert_eq!(BridgeModule::max_validators(), MAX_VALIDATORS);
+            let id = BridgeModule::message_id_by_transfer_id(0);
+            assert_eq!(BridgeModule::limit_messages(id).status, Status::Pending);
+
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V2), 3));
+            assert_eq!(BridgeModule::max_validators(), 3);
+            assert_eq!(BridgeModule::limit_messages(id).status, Status::Confirmed);
+
+            assert_noop!(
+                BridgeModule::add_validator(Origin::signed(V1), V4),
+                "Validators maximum reached."
+            );
+
+            // the same limit can be proposed again once the previous proposal is closed
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V1), 4));
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V2), 4));
+            assert_eq!(BridgeModule::max_validators(), 4);
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V1), 3));
+            assert_ok!(BridgeModule::set_max_validators(Origin::signed(V2), 3));
+            assert_eq!(BridgeModule::max_validators(), 3);
+        })
+    }
+    #[test]
+    fn max_validators_out_of_bounds_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::set_max_validators(Origin::signed(V1), MAX_VALIDATORS + 1),
+                "Validators maximum is above the hard limit."
+            );
+            assert_noop!(
+                BridgeModule::set_max_validators(Origin::signed(V1), 2),
+                "Validators maximum is below current validators count."
+            );
+            assert_noop!(
+                BridgeModule::set_max_validators(Origin::signed(USER2), 10),
+                "Only validators can call this function"
+            );
+        })
+    }
+    #[test]
+    fn is_ready_for_confirmation_should_follow_withdraw_lifecycle() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // confirmed deposit is not a withdraw
+            assert_eq!(BridgeModule::is_ready_for_confirmation(eth_message_id), false);
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(BridgeModule::is_ready_for_confirmation(sub_message_id), false);
+
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Pending);
+            assert_eq!(BridgeModule::is_ready_for_confirmation(sub_message_id), false);
+
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::is_ready_for_confirmation(sub_message_id), true);
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::is_ready_for_confirmation(sub_message_id), false);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::is_ready_for_confirmation(sub_message_id), false);
+
+            let unknown_id = H256::from([7u8; 32]);
+            assert_eq!(BridgeModule::is_ready_for_confirmation(unknown_id), false);
+        })
+    }
+    #[test]
+    fn zero_amount_transfers_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    0
+                ),
+                "Amount must be positive"
+            );
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 0),
+                "Amount must be positive"
+            );
+            assert_eq!(BridgeModule::bridge_transfers_count(), 0);
+        })
+    }
+    #[test]
+    fn partially_signed_mint_can_be_cancelled() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+
+            assert_ok!(BridgeModule::cancel_mint(Origin::signed(V2), message_id));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+            assert_ok!(BridgeModule::cancel_mint(Origin::signed(V3), message_id));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Canceled);
+            assert_eq!(BridgeModule::transfers(0).open, false);
+
+            // remaining validators can't push the mint through anymore
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "This transfer is not open"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_eq!(TokenModule::total_supply(), 0);
+
+            assert_noop!(
+                BridgeModule::cancel_mint(Origin::signed(V1), message_id),
+                "Mint is already cancelled"
+            );
+        })
+    }
+    #[test]
+    fn cancel_executed_mint_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::cancel_mint(Origin::signed(V3), message_id));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+
+            assert_noop!(
+                BridgeModule::cancel_mint(Origin::signed(V2), message_id),
+                "Mint is already executed"
+            );
+
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(2);
+            assert_noop!(
+                BridgeModule::cancel_mint(Origin::signed(V2), sub_message_id),
+                "Only mints can be cancelled"
+            );
+        })
+    }
+    #[test]
+    fn cancel_transfer_with_fee_should_refund_everything() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_withdraw_fee(Origin::ROOT, 100));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_eq!(BridgeModule::locked_fee(sub_message_id), 100);
+
+            assert_ok!(BridgeModule::cancel_transfer(
+                Origin::signed(V3),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Canceled);
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 0);
+            assert_eq!(BridgeModule::locked_fee(sub_message_id), 0);
+            assert_eq!(TokenModule::total_supply(), 1000);
+
+            assert_noop!(
+                BridgeModule::cancel_transfer(Origin::signed(V3), sub_message_id),
+                "Transfer is already cancelled"
+            );
+        })
+    }
+    #[test]
+    fn burn_with_fee_should_pay_fee_account() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_withdraw_fee(Origin::ROOT, 100));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 100),
+                "Amount must exceed the withdraw fee"
+            );
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 100);
+            assert_eq!(TokenModule::total_supply(), 600);
+        })
+    }
+    #[test]
+    fn cancel_not_approved_transfer_should_close_it() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(0);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+
+            // nothing is locked yet, so nothing is unlocked
+            assert_ok!(BridgeModule::cancel_transfer(
+                Origin::signed(V3),
+                sub_message_id
+            ));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(BridgeModule::transfers(0).open, false);
+            assert_noop!(
+                BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id),
+                "This transfer is not open"
+            );
+        })
+    }
+    #[test]
+    fn signing_should_update_validator_last_active() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::validator_accounts(), vec![V1, V2, V3]);
+
+            system::Module::<Test>::set_block_number(5);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::validator_last_active(V2), 5);
+            assert_eq!(BridgeModule::validator_last_active(V1), 0);
+
+            system::Module::<Test>::set_block_number(8);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::validator_last_active(V1), 8);
+            assert_eq!(BridgeModule::validator_last_active(V2), 5);
+        })
+    }
+    #[test]
+    fn stale_validators_should_reflect_inactivity() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::stale_validators(10), Vec::<u64>::new());
+
+            system::Module::<Test>::set_block_number(20);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::stale_validators(10), vec![V2, V3]);
+            assert_eq!(BridgeModule::stale_validators(20), Vec::<u64>::new());
+
+            system::Module::<Test>::set_block_number(40);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V3), V4));
+            // freshly added validator is not stale
+            assert_eq!(BridgeModule::stale_validators(10), vec![V1]);
+        })
+    }
+    #[test]
+    fn mint_and_burn_fees_should_go_to_their_accounts() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_mint_fee_account(Origin::ROOT, Some(MINT_FEE_ACCOUNT)));
+            assert_ok!(BridgeModule::set_burn_fee_account(Origin::ROOT, Some(BURN_FEE_ACCOUNT)));
+            assert_ok!(BridgeModule::set_mint_fee(Origin::ROOT, 10));
+            assert_ok!(BridgeModule::set_withdraw_fee(Origin::ROOT, 100));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 990);
+            assert_eq!(TokenModule::balance_of(MINT_FEE_ACCOUNT), 10);
+            assert_eq!(TokenModule::total_supply(), 1000);
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 490);
+            assert_eq!(TokenModule::balance_of(BURN_FEE_ACCOUNT), 100);
+            assert_eq!(TokenModule::balance_of(MINT_FEE_ACCOUNT), 10);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 0);
+        })
+    }
+    #[test]
+    fn mint_fee_should_fall_back_to_fee_account() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_mint_fee_account(Origin::ROOT, Some(MINT_FEE_ACCOUNT)));
+            assert_ok!(BridgeModule::set_mint_fee_account(Origin::ROOT, None));
+            assert_ok!(BridgeModule::set_mint_fee(Origin::ROOT, 10));
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    10
+                ),
+                "Amount must exceed the mint fee"
+            );
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 990);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 10);
+            assert_eq!(TokenModule::balance_of(MINT_FEE_ACCOUNT), 0);
+        })
+    }
+    #[test]
+    fn approve_transfer_on_deposit_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::approve_transfer(Origin::signed(V1), message_id),
+                "Use multi_signed_mint for deposits"
+            );
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+
+            assert_noop!(
+                BridgeModule::approve_transfer(Origin::signed(V1), H256::from([7u8; 32])),
+                "Transfer message does not exist"
+            );
+        })
+    }
+    #[test]
+    fn withdraw_whitelist_disabled_should_allow_anyone() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_eq!(BridgeModule::withdraw_whitelist_enabled(), false);
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+        })
+    }
+    #[test]
+    fn withdraw_whitelist_enabled_should_reject_others() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::set_withdraw_whitelist_enabled(Origin::signed(OPERATOR), true));
+            assert_ok!(BridgeModule::set_withdraw_whitelisted(Origin::signed(OPERATOR), USER1, true));
+
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500),
+                "Account not permitted to withdraw"
+            );
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER1),
+                eth_address,
+                500
+            ));
+
+            assert_ok!(BridgeModule::set_withdraw_whitelisted(Origin::signed(OPERATOR), USER1, false));
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER1), eth_address, 400),
+                "Account not permitted to withdraw"
+            );
+        })
+    }
+    #[test]
+    fn each_signature_should_emit_signed_event() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(bridge_events(), vec![RawEvent::Signed(0, V2, 1)]);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(
+                bridge_events(),
+                vec![
+                    RawEvent::Signed(0, V2, 1),
+                    RawEvent::MintedBalance(message_id, USER2, 0, 1000),
+                    RawEvent::Minted(message_id),
+                    RawEvent::Signed(0, V1, 2),
+                ]
+            );
+        })
+    }
+    #[test]
+    fn expired_validator_proposal_can_be_resubmitted() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_proposal_ttl(Origin::ROOT, 10));
+
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            let hash = BridgeModule::message_id_by_transfer_id(0);
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Pending);
+
+            // V1's vote is gone with the expired proposal
+            system::Module::<Test>::set_block_number(11);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::transfers(0).open, false);
+            assert_eq!(BridgeModule::transfer_id_by_hash(hash), 1);
+            assert_eq!(BridgeModule::transfers(1).votes, 1);
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Pending);
+            assert_eq!(BridgeModule::validators_count(), 3);
+
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V3), V4));
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Confirmed);
+            assert_eq!(BridgeModule::validators_count(), 4);
+        })
+    }
+    #[test]
+    fn validator_proposal_within_ttl_should_not_expire() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_proposal_ttl(Origin::ROOT, 10));
+
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            system::Module::<Test>::set_block_number(10);
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_eq!(BridgeModule::bridge_transfers_count(), 1);
+            assert_eq!(BridgeModule::validators_count(), 2);
+        })
+    }
+    #[test]
+    fn effective_operational_should_combine_pauses() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert!(BridgeModule::effective_operational(Direction::Deposit));
+            assert!(BridgeModule::effective_operational(Direction::Withdraw));
+
+            assert_ok!(BridgeModule::set_direction_paused(Origin::signed(OPERATOR), Direction::Withdraw, true));
+            assert!(BridgeModule::effective_operational(Direction::Deposit));
+            assert!(!BridgeModule::effective_operational(Direction::Withdraw));
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500),
+                "Withdraws are paused"
+            );
+
+            assert_ok!(BridgeModule::set_direction_paused(Origin::signed(OPERATOR), Direction::Withdraw, false));
+            assert_ok!(BridgeModule::set_direction_paused(Origin::signed(OPERATOR), Direction::Deposit, true));
+            assert!(!BridgeModule::effective_operational(Direction::Deposit));
+            assert!(BridgeModule::effective_operational(Direction::Withdraw));
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Deposits are paused"
+            );
+
+            assert_ok!(BridgeModule::set_direction_paused(Origin::signed(OPERATOR), Direction::Deposit, false));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert!(!BridgeModule::effective_operational(Direction::Deposit));
+            assert!(!BridgeModule::effective_operational(Direction::Withdraw));
+        })
+    }
+    #[test]
+    fn transfer_to_reserved_eth_address_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let bridge_address = H160::from([9u8; 20]);
+
+            assert_ok!(BridgeModule::set_reserved_eth_address(Origin::signed(OPERATOR), bridge_address, true));
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), bridge_address, 500),
+                "Destination is a reserved bridge address"
+            );
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+
+            assert_ok!(BridgeModule::set_reserved_eth_address(Origin::signed(OPERATOR), bridge_address, false));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                bridge_address,
+                500
+            ));
+        })
+    }
+    #[test]
+    fn transfers_paginated_should_return_pages() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            for amount in 1..6 {
+                assert_ok!(BridgeModule::set_transfer(
+                    Origin::signed(USER2),
+                    eth_address,
+                    amount
+                ));
+            }
+            assert_eq!(BridgeModule::bridge_transfers_count(), 5);
+
+            let page = BridgeModule::transfers_paginated(0, 3);
+            assert_eq!(page.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![0, 1, 2]);
+            assert_eq!(page[1].1.message_id, BridgeModule::message_id_by_transfer_id(1));
+
+            // partial final page
+            let page = BridgeModule::transfers_paginated(3, 3);
+            assert_eq!(page.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![3, 4]);
+
+            assert!(BridgeModule::transfers_paginated(5, 3).is_empty());
+            assert!(BridgeModule::transfers_paginated(ProposalId::max_value(), 3).is_empty());
+            assert_eq!(BridgeModule::transfers_paginated(0, 1_000).len(), 5);
+        })
+    }
+    #[test]
+    fn wedged_transfer_can_be_force_closed() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            // burn confirmation started but never finished
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(TokenModule::locked(USER2), 500);
+
+            assert_ok!(BridgeModule::force_close_transfer(Origin::signed(V1), sub_message_id));
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_ok!(BridgeModule::force_close_transfer(Origin::signed(V3), sub_message_id));
+
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Canceled);
+            assert_eq!(BridgeModule::transfers(1).open, false);
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert!(bridge_events().contains(&RawEvent::TransferForceClosed(sub_message_id)));
+
+            // funds are refunded exactly once
+            assert_noop!(
+                BridgeModule::force_close_transfer(Origin::signed(V2), sub_message_id),
+                "Transfer is already cancelled"
+            );
+            assert_noop!(
+                BridgeModule::cancel_transfer(Origin::signed(V2), sub_message_id),
+                "Transfer is already cancelled"
+            );
+            assert_noop!(
+                BridgeModule::confirm_transfer(
+                    Origin::signed(V1),
+                    sub_message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ),
+                "Transfer is already cancelled"
+            );
+            assert_eq!(TokenModule::locked(USER2), 0);
+        })
+    }
+    #[test]
+    fn force_close_executed_transfer_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::force_close_transfer(Origin::signed(V1), eth_message_id),
+                "Transfer is already executed"
+            );
+        })
+    }
+    #[test]
+    fn percentage_without_absolute_minimum_should_not_be_enough() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_min_absolute_votes(Origin::ROOT, 3));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // 2 of 3 is above 51%, but not 3 signatures
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn absolute_minimum_without_percentage_should_not_be_enough() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::min_absolute_votes(), 1);
+            assert_noop!(
+                BridgeModule::set_min_absolute_votes(Origin::ROOT, 0),
+                "Minimal votes must be positive"
+            );
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // 1 signature meets the minimum, but 1 of 3 is below 51%
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn transfer_state_should_follow_lifecycle() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_eq!(BridgeModule::transfer_state(eth_message_id), None);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::transfer_state(eth_message_id), Some(Status::Pending));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::transfer_state(eth_message_id), Some(Status::Confirmed));
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(BridgeModule::transfer_state(sub_message_id), Some(Status::Withdraw));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::transfer_state(sub_message_id), Some(Status::Approved));
+            assert_ok!(BridgeModule::cancel_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::transfer_state(sub_message_id), Some(Status::Canceled));
+
+            assert_eq!(BridgeModule::transfer_state(H256::from([7u8; 32])), None);
+        })
+    }
+    #[test]
+    fn heavy_validator_alone_should_reach_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::validator_weight(V1), 1);
+            assert_eq!(BridgeModule::total_validator_weight(), 3);
+
+            assert_ok!(BridgeModule::set_validator_weight(Origin::ROOT, V1, 10));
+            assert_eq!(BridgeModule::total_validator_weight(), 12);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::transfers(0).votes, 10);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn validator_weight_should_not_change_on_a_drifted_total() {
+        with_externalities(&mut new_test_ext(), || {
+            <TotalValidatorWeight<Test>>::put(0);
+            assert_noop!(
+                BridgeModule::set_validator_weight(Origin::ROOT, V1, 10),
+                "Validator weight exceeds the total"
+            );
+            assert_eq!(BridgeModule::validator_weight(V1), 1);
+        })
+    }
+    #[test]
+    fn light_validators_should_not_reach_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_validator_weight(Origin::ROOT, V1, 10));
+            assert_noop!(
+                BridgeModule::set_validator_weight(Origin::ROOT, USER2, 10),
+                "Only validators can call this function"
+            );
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // 2 of 12
+            assert_eq!(BridgeModule::transfers(0).votes, 2);
+            assert_eq!(BridgeModule::transfers(0).signatures, 2);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn validators_count_should_follow_genesis_accounts() {
+        let mut r = system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .0;
+        r.extend(
+            GenesisConfig::<Test> {
+                validator_accounts: vec![V1, V2],
+                super_admins: vec![],
+                operator: OPERATOR,
+            }
+            .build_storage()
+            .unwrap()
+            .0,
+        );
+        let mut ext: runtime_io::TestExternalities<Blake2Hasher> = r.into();
+
+        with_externalities(&mut ext, || {
+            assert_eq!(BridgeModule::validators_count(), 2);
+            assert_eq!(BridgeModule::total_validator_weight(), 2);
+            assert_eq!(BridgeModule::validator_accounts(), vec![V1, V2]);
+        })
+    }
+    #[test]
+    fn approve_and_confirm_should_pass_both_phases() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+
+            // first vote only counts towards approval
+            assert_ok!(BridgeModule::approve_and_confirm(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Pending);
+            assert_eq!(TokenModule::locked(USER2), 0);
+
+            // approval quorum reached, the same vote opens confirmation
+            assert_ok!(BridgeModule::approve_and_confirm(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Confirmed);
+            assert_eq!(BridgeModule::transfers(2).open, true);
+            assert_eq!(BridgeModule::transfers(2).votes, 1);
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+
+            assert_ok!(BridgeModule::approve_and_confirm(
+                Origin::signed(V3),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+            assert_eq!(TokenModule::total_supply(), 500);
+        })
+    }
+    #[test]
+    fn approve_and_confirm_on_deposit_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::approve_and_confirm(
+                    Origin::signed(V1),
+                    message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ),
+                "Use multi_signed_mint for deposits"
+            );
+        })
+    }
+    #[test]
+    fn vote_overflow_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            let mut transfer = BridgeModule::transfers(0);
+            transfer.votes = MemberId::max_value();
+            <BridgeTransfers<Test>>::insert(0, transfer);
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Vote count overflow"
+            );
+            assert_eq!(BridgeModule::transfers(0).votes, MemberId::max_value());
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn pending_validator_changes_should_list_open_proposals() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_eq!(BridgeModule::open_proposals(), vec![0, 1, 2]);
+
+            let pending = BridgeModule::pending_validator_changes();
+            assert_eq!(pending.len(), 2);
+            assert_eq!(pending[0].0, 1);
+            assert_eq!(pending[0].1.account, V4);
+            assert_eq!(pending[0].1.action, Status::AddValidator);
+            assert_eq!(pending[1].0, 2);
+            assert_eq!(pending[1].1.account, V3);
+            assert_eq!(pending[1].1.action, Status::RemoveValidator);
+
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_eq!(BridgeModule::pending_validator_changes().len(), 1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::pending_validator_changes().len(), 0);
+            assert_eq!(BridgeModule::open_proposals(), vec![0]);
+        })
+    }
+    #[test]
+    fn cancel_burn_confirmation_should_abort_before_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_noop!(
+                BridgeModule::cancel_burn_confirmation(Origin::signed(V1), sub_message_id),
+                "Burn confirmation is not in progress"
+            );
+
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Confirmed);
+
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V3), sub_message_id));
+
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Approved);
+            assert_eq!(BridgeModule::transfers(1).open, false);
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_noop!(
+                BridgeModule::cancel_burn_confirmation(Origin::signed(V2), sub_message_id),
+                "Burn confirmation is not in progress"
+            );
+        })
+    }
+    #[test]
+    fn aborted_burn_confirmation_can_be_restarted() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V3), sub_message_id));
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+        })
+    }
+    #[test]
+    fn rejected_calls_should_be_counted() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 0),
+                "Amount must be positive"
+            );
+            assert_eq!(BridgeModule::rejected_withdraws_count(), 1);
+            assert_eq!(BridgeModule::rejected_mints_count(), 0);
+
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    0
+                ),
+                "Amount must be positive"
+            );
+            assert_eq!(BridgeModule::rejected_mints_count(), 1);
+
+            // unauthorized calls are not counted
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(USER2),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Only validators can call this function"
+            );
+            assert_eq!(BridgeModule::rejected_withdraws_count(), 1);
+            assert_eq!(BridgeModule::failed_executions_count(), 0);
+        })
+    }
+    #[test]
+    fn failed_execution_should_be_counted() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let overflow_message_id = H256::from([7u8; 32]);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::failed_executions_count(), 0);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                overflow_message_id,
+                eth_address,
+                USER2,
+                TokenBalance::max_value()
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                overflow_message_id,
+                eth_address,
+                USER2,
+                TokenBalance::max_value()
+            ));
+            assert_eq!(BridgeModule::failed_executions_count(), 1);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn known_eth_senders_should_accumulate_distinct_senders() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let other_address = H160::from([9u8; 20]);
+            let mint = |id: u8, from: H160, amount: TokenBalance| {
+                let message_id = H256::from([id; 32]);
+                BridgeModule::multi_signed_mint(Origin::signed(V1), message_id, from, USER2, amount)
+                    .and_then(|_| {
+                        BridgeModule::multi_signed_mint(Origin::signed(V2), message_id, from, USER2, amount)
+                    })
+            };
+
+            // disabled by default
+            assert_ok!(mint(1, eth_address, 1000));
+            assert_eq!(BridgeModule::known_eth_senders(), Vec::<H160>::new());
+
+            assert_ok!(BridgeModule::set_track_eth_senders(Origin::ROOT, true));
+            assert_ok!(mint(2, eth_address, 1000));
+            assert_ok!(mint(3, eth_address, 1000));
+            assert_ok!(mint(4, other_address, TokenBalance::max_value()));
+            assert_eq!(BridgeModule::failed_mints(), vec![H256::from([4u8; 32])]);
+            assert_eq!(BridgeModule::known_eth_senders(), vec![eth_address]);
+
+            assert_ok!(mint(5, other_address, 1000));
+            assert_eq!(BridgeModule::known_eth_senders(), vec![eth_address, other_address]);
+        })
+    }
+    #[test]
+    fn set_validators_should_replace_the_set() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V4, USER2]));
+
+            assert_eq!(BridgeModule::validators_count(), 2);
+            assert_eq!(BridgeModule::total_validator_weight(), 2);
+            assert_eq!(BridgeModule::validator_accounts(), vec![V4, USER2]);
+            assert!(BridgeModule::validators(V4));
+            assert!(BridgeModule::validators(USER2));
+            assert!(!BridgeModule::validators(V1));
+            assert_eq!(BridgeModule::validator_weight(V1), 0);
+            assert_eq!(
+                bridge_events(),
+                vec![RawEvent::ValidatorSetReplaced(vec![V4, USER2])]
+            );
+
+            assert_noop!(
+                BridgeModule::pause_bridge(Origin::signed(V1)),
+                "Only validators can call this function"
+            );
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V4)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(USER2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+        })
+    }
+    #[test]
+    fn set_validators_should_discard_votes_of_replaced_validators() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V2, V3, V4]));
+
+            // 2 of 4 is below the quorum, but would be enough for the new set of 2
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V3, V4]));
+            assert_eq!(BridgeModule::transfers(0).votes, 0);
+            assert!(BridgeModule::transfers(0).open);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert!(BridgeModule::transfers(0).open);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn set_validators_should_reject_bad_input() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::set_validators(Origin::signed(V1), vec![V4]),
+                "bad origin: expected to be a root origin"
+            );
+            assert_noop!(
+                BridgeModule::set_validators(Origin::ROOT, vec![]),
+                "Validator set can't be empty"
+            );
+            assert_noop!(
+                BridgeModule::set_validators(Origin::ROOT, vec![V4, V4]),
+                "Duplicate validator in set"
+            );
+            assert_eq!(BridgeModule::validator_accounts(), vec![V1, V2, V3]);
+        })
+    }
+    #[test]
+    fn partial_resume_should_be_dropped_on_pause() {
+        with_externalities(&mut new_test_ext(), || {
+            // resume can't be proposed on a live bridge anymore, so sign it while paused
+            <BridgeIsOperational<Test>>::put(false);
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V1)));
+            <BridgeIsOperational<Test>>::put(true);
+            assert_eq!(BridgeModule::transfers(0).open, true);
+
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_eq!(BridgeModule::transfers(0).open, false);
+            assert_eq!(BridgeModule::open_proposals(), Vec::<ProposalId>::new());
+
+            // stale vote is gone, resume needs a fresh quorum
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+            assert_eq!(BridgeModule::transfers(2).votes, 1);
+            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V3)));
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+        })
+    }
+    #[test]
+    fn mint_above_total_supply_cap_should_wait_for_governance() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let second_message_id = H256::from([7u8; 32]);
+            assert_eq!(BridgeModule::max_total_supply(), TokenBalance::max_value());
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 1500));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::total_supply(), 1000);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                second_message_id,
+                eth_address,
+                USER2,
+                600
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                second_message_id,
+                eth_address,
+                USER2,
+                600
+            ));
+            assert_eq!(TokenModule::total_supply(), 1000);
+            assert_eq!(BridgeModule::messages(second_message_id).status, Status::Approved);
+            assert!(bridge_events().contains(&RawEvent::MintFailed(second_message_id)));
+
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 1600));
+            assert_ok!(BridgeModule::retry_mint(Origin::signed(USER1), second_message_id));
+            assert_eq!(TokenModule::total_supply(), 1600);
+            assert_eq!(BridgeModule::messages(second_message_id).status, Status::Confirmed);
+        })
+    }
+    #[test]
+    fn quorum_config_should_match_configured_values() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_min_absolute_votes(Origin::ROOT, 2));
+            assert_ok!(BridgeModule::set_validator_weight(Origin::ROOT, V1, 5));
+
+            assert_eq!(
+                BridgeModule::quorum_config(),
+                QuorumConfig {
+                    threshold_percent: 51,
+                    min_absolute_votes: 2,
+                    min_validators: 1,
+                    max_validators: MAX_VALIDATORS,
+                    validators_count: 3,
+                    total_weight: 7,
+                }
+            );
+        })
+    }
+    #[test]
+    fn batch_mint_should_not_depend_on_submission_order() {
+        let first = H256::from([1u8; 32]);
+        let second = H256::from([2u8; 32]);
+        let eth_address = H160::from(ETH_ADDRESS);
+        let run = |ordered: bool| {
+            with_externalities(&mut new_test_ext(), || {
+                let mut mints = vec![
+                    (first, eth_address, USER1, 300),
+                    (second, eth_address, USER2, 700),
+                ];
+                if !ordered {
+                    mints.reverse();
+                }
+                assert_ok!(BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V1),
+                    mints.clone()
+                ));
+                mints.reverse();
+                assert_ok!(BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V2),
+                    mints
+                ));
+
+                assert_eq!(BridgeModule::transfer_id_by_hash(first), 0);
+                assert_eq!(BridgeModule::transfer_id_by_hash(second), 1);
+                assert_eq!(TokenModule::balance_of(USER1), 300);
+                assert_eq!(TokenModule::balance_of(USER2), 700);
+                bridge_events()
+            })
+        };
+
+        let events = run(true);
+        assert_eq!(events, run(false));
+        let minted: Vec<_> = events
+            .into_iter()
+            .filter(|e| match e {
+                RawEvent::Minted(_) => true,
+                _ => false,
+            })
+            .collect();
+        assert_eq!(minted, vec![RawEvent::Minted(first), RawEvent::Minted(second)]);
+    }
+    #[test]
+    fn batch_mint_with_duplicates_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_noop!(
+                BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V1),
+                    vec![
+                        (message_id, eth_address, USER2, 1000),
+                        (message_id, eth_address, USER2, 1000),
+                    ]
+                ),
+                "Duplicate message in batch"
+            );
+        })
+    }
+    #[test]
+    fn batch_mint_failing_on_last_entry_should_change_nothing() {
+        with_externalities(&mut new_test_ext(), || {
+            let first = H256::from([1u8; 32]);
+            let second = H256::from([2u8; 32]);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                second,
+                eth_address,
+                USER2,
+                700
+            ));
+            assert_noop!(
+                BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V1),
+                    vec![
+                        (first, eth_address, USER1, 300),
+                        (second, eth_address, USER2, 700)
+                    ]
+                ),
+                "This validator has already signed"
+            );
+            assert!(!<TransferId<Test>>::exists(first));
+            assert_eq!(BridgeModule::bridge_transfers_count(), 1);
+        })
+    }
+    #[test]
+    fn removal_near_minimum_should_warn() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+
+            assert_eq!(BridgeModule::validators_count(), 2);
+            assert!(bridge_events().contains(&RawEvent::ValidatorSetLow(2)));
+        })
+    }
+    #[test]
+    fn removal_above_margin_should_not_warn() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_set_low_margin(Origin::ROOT, 0));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+
+            assert_eq!(BridgeModule::validators_count(), 2);
+            let warned = bridge_events().into_iter().any(|e| match e {
+                RawEvent::ValidatorSetLow(_) => true,
+                _ => false,
+            });
+            assert!(!warned);
+        })
+    }
+    #[test]
+    fn two_validators_removal_should_not_deadlock() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_eq!(BridgeModule::validators_count(), 2);
+
+            assert_noop!(
+                BridgeModule::remove_validator(Origin::signed(V2), V2),
+                "Validator can't vote on its own removal"
+            );
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V2));
+            assert_eq!(BridgeModule::validators_count(), 1);
+            assert!(!BridgeModule::validators(V2));
+            assert_eq!(BridgeModule::total_validator_weight(), 1);
+        })
+    }
+    #[test]
+    fn removal_quorum_should_count_the_target_above_two_validators() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::validators_count(), 4);
+
+            // 2 of 3 remaining validators is still below the quorum of 4
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::validators_count(), 4);
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V3), V4));
+            assert_eq!(BridgeModule::validators_count(), 3);
+        })
+    }
+    // signed by a test key for (ETH_MESSAGE_ID, ETH_ADDRESS, USER2, 1000)
+    const ETH_SIGNER: [u8; 20] = [
+        0x2c, 0x75, 0x36, 0xe3, 0x60, 0x5d, 0x9c, 0x16, 0xa7, 0xa3, 0xd7, 0xb1, 0x89, 0x8e, 0x52,
+        0x93, 0x96, 0xa6, 0x5c, 0x23,
+    ];
+    fn lock_signature() -> EthSignature {
+        EthSignature(
+            [
+                0xbb, 0x50, 0xe2, 0xd8, 0x9a, 0x4e, 0xd7, 0x06, 0x63, 0xd0, 0x80, 0x65, 0x9f, 0xe0,
+                0xad, 0x4b, 0x9b, 0xc3, 0xe0, 0x6c, 0x17, 0xa2, 0x27, 0x43, 0x39, 0x66, 0xcb, 0x59,
+                0xce, 0xee, 0x02, 0x0d,
+            ],
+            [
+                0x39, 0x3d, 0x92, 0xd4, 0x47, 0x04, 0x0e, 0x2f, 0xa2, 0x19, 0x2b, 0xd9, 0xe2, 0x32,
+                0xf0, 0x36, 0x47, 0x72, 0xcb, 0xb9, 0x3a, 0x15, 0x64, 0x6f, 0xca, 0x80, 0x98, 0x91,
+                0xe9, 0x6f, 0xa6, 0x70,
+            ],
+            1,
+        )
+    }
+    #[test]
+    fn mint_with_valid_eth_proof_should_work() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_eth_signer(Origin::ROOT, H160::from(ETH_SIGNER), true));
+
+            assert_ok!(BridgeModule::multi_signed_mint_with_proof(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000,
+                lock_signature()
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint_with_proof(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000,
+                lock_signature()
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn unproven_mint_votes_should_fail_when_proof_is_required() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_eth_signer(Origin::ROOT, H160::from(ETH_SIGNER), true));
+            assert_ok!(BridgeModule::set_mint_proof_required(Origin::ROOT, true));
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Ethereum signature is required"
+            );
+            assert_noop!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER2, 1000)]
+                ),
+                "Ethereum signature is required"
+            );
+            assert_noop!(
+                BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V1),
+                    vec![(message_id, eth_address, USER2, 1000)]
+                ),
+                "Ethereum signature is required"
+            );
+            assert_noop!(
+                BridgeModule::preregister_mint(Origin::signed(V1), message_id, USER2, 1000),
+                "Ethereum signature is required"
+            );
+
+            assert_ok!(BridgeModule::multi_signed_mint_with_proof(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000,
+                lock_signature()
+            ));
+        })
+    }
+    #[test]
+    fn mint_with_invalid_eth_proof_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            // signer isn't trusted yet
+            assert_noop!(
+                BridgeModule::multi_signed_mint_with_proof(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000,
+                    lock_signature()
+                ),
+                "Invalid ethereum signature"
+            );
+
+            assert_ok!(BridgeModule::set_eth_signer(Origin::ROOT, H160::from(ETH_SIGNER), true));
+            // signature doesn't cover the amount
+            assert_noop!(
+                BridgeModule::multi_signed_mint_with_proof(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    999,
+                    lock_signature()
+                ),
+                "Invalid ethereum signature"
+            );
+            let mut broken = lock_signature();
+            broken.0 = [0u8; 32];
+            assert_noop!(
+                BridgeModule::multi_signed_mint_with_proof(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000,
+                    broken
+                ),
+                "Invalid ethereum signature"
+            );
+        })
+    }
+    #[test]
+    fn has_signed_should_reflect_votes() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert!(BridgeModule::has_signed(0, V1));
+            assert!(!BridgeModule::has_signed(0, V2));
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "This validator has already signed"
+            );
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert!(BridgeModule::has_signed(0, V2));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn burn_confirmation_should_reset_signers() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert!(BridgeModule::has_signed(1, V1));
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            // approval signers stay on record, confirmation has its own
+            assert!(BridgeModule::has_signed(1, V1));
+            assert!(!BridgeModule::has_signed(2, V1));
+            assert!(BridgeModule::has_signed(2, V2));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+        })
+    }
+    #[test]
+    fn min_transfer_amount_in_tokens_should_use_decimals() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let one_token: TokenBalance = 1_000_000_000_000_000_000;
+            assert_eq!(TokenModule::token_default().decimals, 18);
+
+            assert_ok!(BridgeModule::set_min_transfer_amount_in_tokens(Origin::ROOT, 1));
+            assert_eq!(BridgeModule::min_transfer_amount(), one_token);
+            assert_noop!(
+                BridgeModule::set_min_transfer_amount_in_tokens(Origin::ROOT, 19),
+                "Overflow converting tokens to base units"
+            );
+
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    one_token - 1
+                ),
+                "Amount is below the minimum"
+            );
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                one_token
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                one_token
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), one_token);
+
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, one_token / 2),
+                "Amount is below the minimum"
+            );
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                one_token
+            ));
+        })
+    }
+    #[test]
+    fn bootstrap_can_be_finalized_once() {
+        with_externalities(&mut new_test_ext(), || {
+            assert!(BridgeModule::bootstrapping());
+            assert_ok!(BridgeModule::finalize_bootstrap(Origin::ROOT));
+            assert!(!BridgeModule::bootstrapping());
+            assert_noop!(
+                BridgeModule::finalize_bootstrap(Origin::ROOT),
+                "Bootstrap is already finalized"
+            );
+        })
+    }
+    #[test]
+    fn failed_mint_should_be_retried_once() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 500));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint is not queued for retry"
+            );
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::failed_mints(), vec![message_id]);
+            assert_eq!(BridgeModule::transfers(0).open, false);
+
+            // still blocked by the cap
+            assert_err!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint would exceed the total supply cap"
+            );
+            assert_eq!(BridgeModule::retry_count(message_id), 1);
+
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 1000));
+            assert_ok!(BridgeModule::retry_mint(Origin::signed(USER1), message_id));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert_eq!(BridgeModule::failed_mints(), Vec::<H256>::new());
+
+            assert_noop!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint is not queued for retry"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn validator_should_set_own_metadata() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::validator_metadata(V1), Vec::<u8>::new());
+
+            assert_ok!(BridgeModule::set_validator_metadata(
+                Origin::signed(V1),
+                b"https://relayer-1.example".to_vec()
+            ));
+            assert_eq!(
+                BridgeModule::validator_metadata(V1),
+                b"https://relayer-1.example".to_vec()
+            );
+            assert_ok!(BridgeModule::set_validator_metadata(
+                Origin::signed(V1),
+                b"https://relayer-2.example".to_vec()
+            ));
+            assert_eq!(
+                BridgeModule::validator_metadata(V1),
+                b"https://relayer-2.example".to_vec()
+            );
+            assert_eq!(BridgeModule::validator_metadata(V2), Vec::<u8>::new());
+
+            assert_noop!(
+                BridgeModule::set_validator_metadata(Origin::signed(V1), vec![0u8; 257]),
+                "Validator metadata is too long"
+            );
+        })
+    }
+    #[test]
+    fn non_validator_should_not_set_metadata() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::set_validator_metadata(Origin::signed(USER2), b"url".to_vec()),
+                "Only validators can call this function"
+            );
+        })
+    }
+    #[test]
+    fn resume_operational_bridge_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::resume_bridge(Origin::signed(V1)),
+                "Bridge is already operational"
+            );
+            assert_eq!(BridgeModule::bridge_transfers_count(), 0);
+        })
+    }
+    #[test]
+    fn pending_burn_total_should_track_in_flight_withdraws() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 300));
+            let first = BridgeModule::message_id_by_transfer_id(1);
+            let second = BridgeModule::message_id_by_transfer_id(2);
+            assert_eq!(BridgeModule::pending_burn_total(), 0);
+
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), first));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), first));
+            assert_eq!(BridgeModule::pending_burn_total(), 500);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), second));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), second));
+            assert_eq!(BridgeModule::pending_burn_total(), 800);
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                first,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::pending_burn_total(), 800);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                first,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::pending_burn_total(), 300);
+
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V1), second));
+            assert_eq!(BridgeModule::pending_burn_total(), 0);
+        })
+    }
+    #[test]
+    fn super_admins_should_replace_deadlocked_validator_set() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::super_replace_validators(Origin::signed(V1), vec![V4]),
+                "Only super admins can call this function"
+            );
+
+            assert_ok!(BridgeModule::super_replace_validators(Origin::signed(SA1), vec![V4]));
+            assert_eq!(BridgeModule::validator_accounts(), vec![V1, V2, V3]);
+            assert_noop!(
+                BridgeModule::super_replace_validators(Origin::signed(SA1), vec![V4]),
+                "This super admin has already voted"
+            );
+
+            assert_ok!(BridgeModule::super_replace_validators(Origin::signed(SA2), vec![V4]));
+            assert_eq!(BridgeModule::validator_accounts(), vec![V4]);
+            assert_eq!(BridgeModule::validators_count(), 1);
+            assert!(!BridgeModule::validators(V1));
+
+            // epoch moved on, leftover votes don't count
+            assert_ok!(BridgeModule::super_replace_validators(Origin::signed(SA3), vec![V4]));
+            assert_eq!(BridgeModule::super_admin_epoch(), 1);
+        })
+    }
+    #[test]
+    fn super_replaced_validators_should_not_execute_anything() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+
+            // a single vote is the quorum of the new set
+            assert_ok!(BridgeModule::super_replace_validators(Origin::signed(SA1), vec![V4]));
+            assert_ok!(BridgeModule::super_replace_validators(Origin::signed(SA2), vec![V4]));
+            assert_eq!(BridgeModule::transfers(0).votes, 0);
+            assert_eq!(BridgeModule::transfers(1).votes, 0);
+            assert!(BridgeModule::transfers(0).open);
+            assert!(BridgeModule::bridge_is_operational());
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V4),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn super_admins_should_change_only_their_own_set() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::super_set_admins(Origin::signed(V1), vec![V1]),
+                "Only super admins can call this function"
+            );
+            assert_ok!(BridgeModule::super_set_admins(Origin::signed(SA1), vec![SA1, SA2]));
+            assert_ok!(BridgeModule::super_set_admins(Origin::signed(SA3), vec![SA1, SA2]));
+            assert_eq!(BridgeModule::super_admins(), vec![SA1, SA2]);
+
+            assert_noop!(
+                BridgeModule::super_replace_validators(Origin::signed(SA3), vec![V4]),
+                "Only super admins can call this function"
+            );
+        })
+    }
+    #[test]
+    fn required_votes_should_round_up() {
+        with_externalities(&mut new_test_ext(), || {
+            let expected: Vec<MemberId> = vec![1, 2, 2, 3, 3, 4, 4, 5, 5, 6];
+            let required: Vec<MemberId> = (1..=10u64)
+                .map(|count| {
+                    let accounts: Vec<u64> = (100..100 + count).collect();
+                    assert_ok!(BridgeModule::set_validators(Origin::ROOT, accounts));
+                    BridgeModule::required_votes()
+                })
+                .collect();
+            assert_eq!(required, expected);
+        })
+    }
+    #[test]
+    fn half_of_validators_should_not_reach_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V2, V3, V4]));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn effective_params_should_resolve_current_rules() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_withdraw_fee(Origin::ROOT, 10));
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 5000));
+
+            let params = BridgeModule::effective_params(USER2, Direction::Withdraw, 500);
+            assert_eq!(
+                params,
+                EffectiveParams {
+                    fee: 10,
+                    min_amount: 0,
+                    supply_headroom: 5000,
+                    bootstrapping: true,
+                    allowed: true,
+                }
+            );
+            assert!(!BridgeModule::effective_params(USER2, Direction::Deposit, 6000).allowed);
+
+            // whitelisted account keeps withdrawing, others don't
+            assert_ok!(BridgeModule::set_withdraw_whitelist_enabled(Origin::signed(OPERATOR), true));
+            assert_ok!(BridgeModule::set_withdraw_whitelisted(Origin::signed(OPERATOR), USER1, true));
+            assert!(BridgeModule::effective_params(USER1, Direction::Withdraw, 500).allowed);
+            assert!(!BridgeModule::effective_params(USER2, Direction::Withdraw, 500).allowed);
+
+            // paused direction
+            assert_ok!(BridgeModule::set_direction_paused(Origin::signed(OPERATOR), Direction::Deposit, true));
+            assert!(!BridgeModule::effective_params(USER2, Direction::Deposit, 500).allowed);
+
+            assert_ok!(BridgeModule::finalize_bootstrap(Origin::ROOT));
+            let params = BridgeModule::effective_params(USER1, Direction::Withdraw, 500);
+            assert!(!params.bootstrapping);
+            assert!(params.allowed);
+        })
+    }
+    #[test]
+    fn confirm_transfer_should_record_agreed_unlock_tx() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let unlock_tx = H256::from(ETH_UNLOCK_TX);
+            let other_tx = H256::from([9u8; 32]);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                unlock_tx
+            ));
+            assert_noop!(
+                BridgeModule::confirm_transfer(Origin::signed(V1), sub_message_id, other_tx),
+                "Unlock tx hash mismatch"
+            );
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                unlock_tx
+            ));
+
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+            assert_eq!(BridgeModule::eth_unlock_tx(sub_message_id), unlock_tx);
+            assert!(bridge_events().contains(&RawEvent::Burned(
+                sub_message_id,
+                USER2,
+                eth_address,
+                500,
+                unlock_tx
+            )));
+        })
+    }
+    #[test]
+    fn hooks_should_be_called_on_mint_and_burn() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // no quorum yet
+            assert!(MINTED.with(|v| v.borrow().is_empty()));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(MINTED.with(|v| v.borrow().clone()), vec![(USER2, 1000)]);
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id
+                ));
+            }
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::confirm_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ));
+            }
+            assert_eq!(BURNED.with(|v| v.borrow().clone()), vec![(USER2, 500)]);
+            assert_eq!(MINTED.with(|v| v.borrow().len()), 1);
+        })
+    }
+    #[test]
+    fn prune_finalized_should_remove_old_finalized_transfers() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let first = H256::from([1u8; 32]);
+            let second = H256::from([2u8; 32]);
+            let open = H256::from([3u8; 32]);
+
+            for message_id in &[first, second] {
+                for validator in &[V1, V2] {
+                    assert_ok!(BridgeModule::multi_signed_mint(
+                        Origin::signed(*validator),
+                        *message_id,
+                        eth_address,
+                        USER2,
+                        1000
+                    ));
+                }
+            }
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                open,
+                eth_address,
+                USER2,
+                1000
+            ));
+            system::Module::<Test>::set_block_number(5);
+
+            // only one record per call
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 1));
+            assert!(!<TransferMessages<Test>>::exists(first));
+            assert!(!<BridgeTransfers<Test>>::exists(0));
+            assert!(<TransferMessages<Test>>::exists(second));
+            assert!(BridgeModule::processed_message(first));
+            assert_eq!(
+                BridgeModule::transfers_with_status(Status::Confirmed),
+                vec![second]
+            );
+
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 10));
+            assert!(!<TransferMessages<Test>>::exists(second));
+            assert!(BridgeModule::transfers_with_status(Status::Confirmed).is_empty());
+            assert!(!<TransferStatusPosition<Test>>::exists(second));
+            // next call starts at the open proposal, pruned ones aren't listed
+            assert_eq!(BridgeModule::prune_cursor(), 2);
+            let page = BridgeModule::transfers_paginated(0, 3);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].0, 2);
+            // open proposal stays
+            assert!(<TransferMessages<Test>>::exists(open));
+            assert!(BridgeModule::transfers(2).open);
+            assert!(!BridgeModule::processed_message(open));
+
+            // pruned message can't be replayed
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    first,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Message already processed"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 2000);
+        })
+    }
+
+    #[test]
+    fn prune_finalized_should_look_at_most_limit_transfers() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let open = H256::from([3u8; 32]);
+            let first = H256::from([1u8; 32]);
+            let second = H256::from([2u8; 32]);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                open,
+                eth_address,
+                USER2,
+                1000
+            ));
+            for message_id in &[first, second] {
+                for validator in &[V1, V2] {
+                    assert_ok!(BridgeModule::multi_signed_mint(
+                        Origin::signed(*validator),
+                        *message_id,
+                        eth_address,
+                        USER2,
+                        1000
+                    ));
+                }
+            }
+            system::Module::<Test>::set_block_number(5);
+
+            // the open proposal uses up the first call
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 1));
+            assert!(<TransferMessages<Test>>::exists(first));
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 1));
+            assert!(!<TransferMessages<Test>>::exists(first));
+            assert!(<TransferMessages<Test>>::exists(second));
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 1));
+            assert!(!<TransferMessages<Test>>::exists(second));
+            assert_eq!(BridgeModule::prune_cursor(), 0);
+            assert_eq!(BridgeModule::prune_scan_cursor(), 0);
+            assert!(<TransferMessages<Test>>::exists(open));
+        })
+    }
+    #[test]
+    fn prune_finalized_should_keep_recent_transfers() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            system::Module::<Test>::set_block_number(5);
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 10));
+            assert!(<TransferMessages<Test>>::exists(eth_message_id));
+            assert_noop!(
+                BridgeModule::prune_finalized(Origin::signed(V1), 6, 10),
+                "bad origin: expected to be a root origin"
+            );
+        })
+    }
+    #[test]
+    fn multi_signed_mint_split_should_mint_to_every_recipient() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let recipients = vec![(USER1, 300), (USER2, 700)];
+
+            assert_ok!(BridgeModule::multi_signed_mint_split(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                1000,
+                recipients.clone()
+            ));
+            assert_noop!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER1, 700), (USER2, 300)]
+                ),
+                "Recipients mismatch"
+            );
+            assert_eq!(TokenModule::total_supply(), 0);
+            assert_ok!(BridgeModule::multi_signed_mint_split(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                1000,
+                recipients
+            ));
+
+            assert_eq!(TokenModule::balance_of(USER1), 300);
+            assert_eq!(TokenModule::balance_of(USER2), 700);
+            assert_eq!(BridgeModule::messages(eth_message_id).status, Status::Confirmed);
+        })
+    }
+
+    #[test]
+    fn multi_signed_mint_split_should_check_the_total() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V1),
+                    eth_message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER1, 300), (USER2, 600)]
+                ),
+                "Recipient amounts don't sum up to the total"
+            );
+            assert_noop!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V1),
+                    eth_message_id,
+                    eth_address,
+                    1000,
+                    vec![]
+                ),
+                "Recipients list is empty"
+            );
+        })
+    }
+    #[test]
+    fn mint_to_fresh_account_should_ignore_existential_deposit() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            // no balances entry, well below the balances existential deposit
+            let fresh_account = 99;
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    eth_message_id,
+                    eth_address,
+                    fresh_account,
+                    1
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(fresh_account), 1);
+            assert_eq!(balances::Module::<Test>::free_balance(fresh_account), 0);
+        })
+    }
+    #[test]
+    fn transfers_with_status_should_follow_withdraw_phases() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(
+                BridgeModule::transfers_with_status(Status::Confirmed),
+                vec![eth_message_id]
+            );
+            assert!(BridgeModule::transfers_with_status(Status::Deposit).is_empty());
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(
+                BridgeModule::transfers_with_status(Status::Withdraw),
+                vec![sub_message_id]
+            );
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id
+                ));
+            }
+            assert!(BridgeModule::transfers_with_status(Status::Withdraw).is_empty());
+            assert_eq!(
+                BridgeModule::transfers_with_status(Status::Approved),
+                vec![sub_message_id]
+            );
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::confirm_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ));
+            }
+            assert!(BridgeModule::transfers_with_status(Status::Approved).is_empty());
+            assert_eq!(
+                BridgeModule::transfers_with_status(Status::Confirmed),
+                vec![eth_message_id, sub_message_id]
+            );
+        })
+    }
+    #[test]
+    fn mint_with_timestamp_should_reject_old_events() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let old_message_id = H256::from([1u8; 32]);
+            let eth_address = H160::from(ETH_ADDRESS);
+            timestamp::Module::<Test>::set_timestamp(10_000);
+            assert_ok!(BridgeModule::set_max_mint_age(Origin::ROOT, 3600));
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Lock timestamp is required"
+            );
+            assert_noop!(
+                BridgeModule::multi_signed_mint_with_timestamp(
+                    Origin::signed(V1),
+                    old_message_id,
+                    eth_address,
+                    USER2,
+                    1000,
+                    5_000
+                ),
+                "Mint message is too old"
+            );
+
+            assert_ok!(BridgeModule::multi_signed_mint_with_timestamp(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000,
+                9_000
+            ));
+            assert_noop!(
+                BridgeModule::multi_signed_mint_with_timestamp(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000,
+                    9_500
+                ),
+                "Lock timestamp mismatch"
+            );
+            assert_ok!(BridgeModule::multi_signed_mint_with_timestamp(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000,
+                9_000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn confirm_transfer_out_of_phase_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let unlock_tx = H256::from(ETH_UNLOCK_TX);
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_noop!(
+                BridgeModule::confirm_transfer(Origin::signed(V1), eth_message_id, unlock_tx),
+                "Only withdraws can be confirmed"
+            );
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(BridgeModule::messages(sub_message_id).phase, WithdrawPhase::Requested);
+            assert_noop!(
+                BridgeModule::confirm_transfer(Origin::signed(V1), sub_message_id, unlock_tx),
+                "This transfer must be approved first."
+            );
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id
+                ));
+            }
+            assert_eq!(BridgeModule::messages(sub_message_id).phase, WithdrawPhase::Approved);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                unlock_tx
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).phase, WithdrawPhase::Confirming);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                unlock_tx
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).phase, WithdrawPhase::Burned);
+
+            // burned withdraw can't be reopened for another burn
+            assert_noop!(
+                BridgeModule::confirm_transfer(Origin::signed(V3), sub_message_id, unlock_tx),
+                "Transfer is already burned"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+        })
+    }
+    #[test]
+    fn shadow_validator_votes_should_not_count_until_promoted() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let first = H256::from([1u8; 32]);
+            let second = H256::from([2u8; 32]);
+
+            assert_ok!(BridgeModule::set_shadow_validator(Origin::ROOT, V3, true));
+            // two voting validators left, both have to agree
+            assert_eq!(BridgeModule::required_votes(), 2);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                first,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                first,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::shadow_votes(0), vec![V3]);
+            assert!(!BridgeModule::has_signed(0, V3));
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V3),
+                    first,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "This validator has already signed"
+            );
+
+            assert_ok!(BridgeModule::set_shadow_validator(Origin::ROOT, V3, false));
+            assert_eq!(BridgeModule::total_validator_weight(), 3);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                second,
+                eth_address,
+                USER2,
+                500
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                second,
+                eth_address,
+                USER2,
+                500
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+        })
+    }
+    #[test]
+    fn fraud_report_should_pay_bounty_when_confirmed() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_fraud_bounty(Origin::ROOT, 50));
+            assert_ok!(TokenModule::_mint(FEE_ACCOUNT, 100));
+            assert_ok!(TokenModule::_mint(USER2, 1000));
+
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(0);
+            assert_ok!(BridgeModule::report_fraud(
+                Origin::signed(USER1),
+                sub_message_id,
+                b"evidence".to_vec()
+            ));
+            assert_noop!(
+                BridgeModule::report_fraud(Origin::signed(V1), sub_message_id, vec![]),
+                "Transfer is already reported"
+            );
+
+            assert_ok!(BridgeModule::confirm_fraud(Origin::signed(V3), sub_message_id));
+            assert_eq!(TokenModule::balance_of(USER1), 0);
+            assert_ok!(BridgeModule::confirm_fraud(Origin::signed(V1), sub_message_id));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Canceled);
+            assert_eq!(TokenModule::balance_of(USER1), 50);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 50);
+            assert!(bridge_events().contains(&RawEvent::FraudBountyPaid(
+                sub_message_id,
+                USER1,
+                50
+            )));
+        })
+    }
+
+    #[test]
+    fn fraud_report_of_executed_transfer_should_not_pay() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_fraud_bounty(Origin::ROOT, 50));
+            assert_ok!(TokenModule::_mint(FEE_ACCOUNT, 100));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::report_fraud(
+                Origin::signed(USER1),
+                eth_message_id,
+                vec![]
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+
+            assert_eq!(TokenModule::balance_of(USER1), 0);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 100);
+            assert_noop!(
+                BridgeModule::report_fraud(Origin::signed(USER2), eth_message_id, vec![]),
+                "Transfer is already executed"
+            );
+        })
+    }
+    #[test]
+    fn signature_bits_should_track_signers() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::validator_index(V1), 0);
+            assert_eq!(BridgeModule::validator_index(V3), 2);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::signature_bits(0), vec![0b100]);
+            assert!(BridgeModule::has_signed(0, V3));
+            assert!(!BridgeModule::has_signed(0, V1));
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V3),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "This validator has already signed"
+            );
+        })
+    }
+
+    #[test]
+    fn removed_validator_index_should_not_be_reused() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V3, V4]));
+            // V4 gets a fresh index, V2 keeps its own but not its vote
+            assert_eq!(BridgeModule::validator_index(V4), 3);
+            assert_eq!(BridgeModule::validator_index(V2), 1);
+            assert_eq!(BridgeModule::indexed_validator(1), V2);
+            assert!(!BridgeModule::has_signed(0, V4));
+            assert!(!BridgeModule::has_signed(0, V2));
+            assert_eq!(BridgeModule::transfers(0).votes, 0);
+            assert_eq!(BridgeModule::transfers(0).signatures, 0);
+
+            // one vote of the remaining set is below the quorum of 2
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V4),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert!(BridgeModule::has_signed(0, V4));
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+            assert!(BridgeModule::transfers(0).open);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn fees_should_accrue_into_day_buckets() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_mint_fee(Origin::ROOT, 10));
+            let mint = |id: u8| {
+                for validator in &[V1, V2] {
+                    assert_ok!(BridgeModule::multi_signed_mint(
+                        Origin::signed(*validator),
+                        H256::from([id; 32]),
+                        eth_address,
+                        USER2,
+                        1000
+                    ));
+                }
+            };
+
+            timestamp::Module::<Test>::set_timestamp(100);
+            mint(1);
+            timestamp::Module::<Test>::set_timestamp(DAY_SECONDS + 100);
+            assert_eq!(BridgeModule::current_day_index(), 1);
+            mint(2);
+            mint(3);
+
+            assert_eq!(BridgeModule::daily_fees(0), 10);
+            assert_eq!(BridgeModule::daily_fees(1), 20);
+            assert_eq!(BridgeModule::fees_between(0, 0), 10);
+            assert_eq!(BridgeModule::fees_between(0, 1), 30);
+            assert_eq!(BridgeModule::fees_between(1, 7), 20);
+            assert_eq!(BridgeModule::fees_between(2, 7), 0);
+
+            // old buckets are pruned on the next fee
+            timestamp::Module::<Test>::set_timestamp(DAY_SECONDS * (FEE_RETENTION_DAYS + 1) + 100);
+            mint(4);
+            assert_eq!(BridgeModule::fee_days(), vec![1, FEE_RETENTION_DAYS + 1]);
+            assert_eq!(BridgeModule::daily_fees(0), 0);
+            assert_eq!(BridgeModule::fees_between(0, FEE_RETENTION_DAYS + 1), 30);
+        })
+    }
+    #[test]
+    fn preregistered_mint_should_be_fast_tracked() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::preregister_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::preregister_mint(Origin::signed(V2), eth_message_id, USER2, 1000),
+                "Mint is already pre-registered"
+            );
+            assert_noop!(
+                BridgeModule::preregister_mint(Origin::signed(USER1), eth_message_id, USER2, 1000),
+                "Only validators can call this function"
+            );
+
+            // the first relayed vote executes, V1's pre-registration counts as its vote
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert!(BridgeModule::has_signed(0, V1));
+            assert_eq!(BridgeModule::expected_mint(eth_message_id), None);
+        })
+    }
+
+    #[test]
+    fn mint_not_matching_preregistration_should_fail() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::preregister_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    999
+                ),
+                "Mint doesn't match pre-registration"
+            );
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    USER1,
+                    1000
+                ),
+                "Mint doesn't match pre-registration"
+            );
+            assert_noop!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V2),
+                    eth_message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER1, 1000)]
+                ),
+                "Mint doesn't match pre-registration"
+            );
+            assert_noop!(
+                BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V2),
+                    vec![(eth_message_id, eth_address, USER2, 999)]
+                ),
+                "Mint doesn't match pre-registration"
+            );
+        })
+    }
+    #[test]
+    fn preregistered_mint_should_be_dropped_by_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_noop!(
+                BridgeModule::drop_preregistered_mint(Origin::signed(V2), eth_message_id),
+                "Mint is not pre-registered"
+            );
+
+            assert_ok!(BridgeModule::preregister_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                USER2,
+                999
+            ));
+            assert_ok!(BridgeModule::drop_preregistered_mint(
+                Origin::signed(V2),
+                eth_message_id
+            ));
+            assert!(BridgeModule::expected_mint(eth_message_id).is_some());
+            assert_ok!(BridgeModule::drop_preregistered_mint(
+                Origin::signed(V3),
+                eth_message_id
+            ));
+            assert_eq!(BridgeModule::expected_mint(eth_message_id), None);
+
+            // it can be pre-registered and dropped again
+            assert_ok!(BridgeModule::preregister_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                USER2,
+                999
+            ));
+            assert_ok!(BridgeModule::drop_preregistered_mint(
+                Origin::signed(V2),
+                eth_message_id
+            ));
+            assert_ok!(BridgeModule::drop_preregistered_mint(
+                Origin::signed(V3),
+                eth_message_id
+            ));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn failed_execution_should_leave_proposal_open_for_retry() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(TokenModule::_mint(USER2, 1000));
+            assert_ok!(BridgeModule::set_transfer(
+                Origin::signed(USER2),
+                eth_address,
+                500
+            ));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(0);
+            // funds leave before approval
+            assert_ok!(TokenModule::transfer(Origin::signed(USER2), USER1, 800));
+
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V1),
+                sub_message_id
+            ));
+            assert_err!(
+                BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id),
+                "Not enough free balance to lock"
+            );
+            assert_eq!(BridgeModule::failed_executions_count(), 1);
+            assert!(BridgeModule::transfers(0).open);
+            assert_eq!(BridgeModule::transfers(0).votes, 1);
+            assert!(!BridgeModule::has_signed(0, V2));
+            assert_eq!(TokenModule::locked(USER2), 0);
+
+            assert_ok!(TokenModule::transfer(Origin::signed(USER1), USER2, 800));
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(V2),
+                sub_message_id
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Approved);
+            assert_eq!(TokenModule::locked(USER2), 500);
+        })
+    }
+    #[test]
+    fn transfers_to_other_chains_should_be_tracked_per_chain() {
+        with_externalities(&mut new_test_ext(), || {
+            let address = b"destination".to_vec();
+            assert_ok!(TokenModule::_mint(USER2, 1000));
+
+            assert_ok!(BridgeModule::set_transfer_to_chain(
+                Origin::signed(USER2),
+                2,
+                address.clone(),
+                300
+            ));
+            assert_ok!(BridgeModule::set_transfer_to_chain(
+                Origin::signed(USER2),
+                3,
+                address.clone(),
+                300
+            ));
+            let first = BridgeModule::message_id_by_transfer_id(0);
+            let second = BridgeModule::message_id_by_transfer_id(1);
+            assert!(first != second);
+            assert_eq!(BridgeModule::transfer_destination(first), Some((2, address.clone())));
+            assert_eq!(BridgeModule::transfer_destination(second), Some((3, address.clone())));
+            assert!(bridge_events().contains(&RawEvent::ChainRelayMessage(first, 2)));
+            assert!(bridge_events().contains(&RawEvent::ChainRelayMessage(second, 3)));
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    second
+                ));
+            }
+            assert!(bridge_events().contains(&RawEvent::ApprovedChainRelayMessage(
+                second,
+                USER2,
+                3,
+                address,
+                300
+            )));
+            assert_eq!(BridgeModule::messages(first).status, Status::Withdraw);
+            assert_eq!(TokenModule::locked(USER2), 300);
+        })
+    }
+
+    #[test]
+    fn transfer_to_ethereum_chain_id_should_use_eth_path() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_transfer_to_chain(
+                Origin::signed(USER2),
+                ETHEREUM_CHAIN_ID,
+                ETH_ADDRESS.to_vec(),
+                300
+            ));
+            let message_id = BridgeModule::message_id_by_transfer_id(0);
+            assert_eq!(BridgeModule::messages(message_id).eth_address, eth_address);
+            assert_eq!(BridgeModule::transfer_destination(message_id), None);
+            assert!(bridge_events().contains(&RawEvent::RelayMessage(message_id)));
+
+            assert_noop!(
+                BridgeModule::set_transfer_to_chain(
+                    Origin::signed(USER2),
+                    ETHEREUM_CHAIN_ID,
+                    vec![1, 2, 3],
+                    300
+                ),
+                "Invalid ethereum address"
+            );
+            assert_noop!(
+                BridgeModule::set_transfer_to_chain(Origin::signed(USER2), 2, vec![], 300),
+                "Invalid destination address"
+            );
+        })
+    }
+    #[test]
+    fn large_transfers_should_need_more_votes() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            let small = H256::from([1u8; 32]);
+            let large = H256::from([2u8; 32]);
+            assert_ok!(BridgeModule::set_amount_tiers(Origin::ROOT, vec![(1000, 1)]));
+            assert_eq!(BridgeModule::required_votes_for(1000), 2);
+            assert_eq!(BridgeModule::required_votes_for(1001), 3);
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    small,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    large,
+                    eth_address,
+                    USER2,
+                    5000
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert!(BridgeModule::transfers(1).open);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V3),
+                large,
+                eth_address,
+                USER2,
+                5000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 6000);
+        })
+    }
+
+    #[test]
+    fn set_amount_tiers_should_reject_unsorted_thresholds() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::set_amount_tiers(Origin::ROOT, vec![(1000, 1), (500, 2)]),
+                "Amount tiers must be in increasing order"
+            );
+        })
+    }
+    #[test]
+    fn total_validators_ever_added_should_only_grow() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::total_validators_ever_added(), 3);
+
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::total_validators_ever_added(), 4);
+
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V4), V3));
+            assert!(!BridgeModule::validators(V3));
+            assert_eq!(BridgeModule::validators_count(), 3);
+            assert_eq!(BridgeModule::total_validators_ever_added(), 4);
+
+            // only accounts new to the set are counted
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V2, USER2]));
+            assert_eq!(BridgeModule::total_validators_ever_added(), 5);
+        })
+    }
+    #[test]
+    fn removing_validator_should_execute_proposals_reaching_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            // 2 of 4 is below the quorum
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+
+            // 2 of 3 is enough, the mint runs with the removal
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V4), V3));
+            assert_eq!(BridgeModule::validators_count(), 3);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(BridgeModule::transfers(1).open, false);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert!(!BridgeModule::open_proposals().contains(&1));
+        })
+    }
+    #[test]
+    fn reevaluation_should_be_spread_over_blocks() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            // 2 of 4 votes on more proposals than one block re-checks
+            let count = MAX_REEVALUATIONS_PER_BLOCK + 1;
+            for i in 0..count {
+                let mut bytes = [1u8; 32];
+                bytes[0] = i as u8;
+                for validator in &[V1, V2] {
+                    assert_ok!(BridgeModule::multi_signed_mint(
+                        Origin::signed(*validator),
+                        H256::from(bytes),
+                        eth_address,
+                        USER2,
+                        1000
+                    ));
+                }
+            }
+            assert_eq!(BridgeModule::open_proposals_count(), count);
+
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V4), V3));
+            // the removal proposal itself takes one of the checks
+            assert_eq!(BridgeModule::open_proposals_count(), 2);
+            assert_eq!(TokenModule::balance_of(USER2), 1000 * (count - 2));
+
+            BridgeModule::on_initialize(2);
+            assert_eq!(BridgeModule::open_proposals_count(), 0);
+            assert_eq!(BridgeModule::reevaluation_cursor(), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 1000 * count);
+        })
+    }
+    #[test]
+    fn removing_validators_should_not_execute_their_own_votes() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            // 2 of 4 is below the quorum, only V3 and V4 sign
+            for validator in &[V3, V4] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            for validator in &[V1, V2, V3] {
+                assert_ok!(BridgeModule::remove_validator(Origin::signed(*validator), V4));
+            }
+            assert_eq!(BridgeModule::validators_count(), 3);
+            assert_eq!(BridgeModule::transfers(1).votes, 1);
+            assert!(BridgeModule::transfers(1).open);
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::remove_validator(Origin::signed(*validator), V3));
+            }
+            assert_eq!(BridgeModule::validators_count(), 2);
+            assert_eq!(BridgeModule::transfers(1).votes, 0);
+            assert!(BridgeModule::transfers(1).open);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn removing_validator_should_keep_proposals_below_quorum_open() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+
+            // 1 of 2 is still short
+            assert_eq!(BridgeModule::validators_count(), 2);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_eq!(BridgeModule::transfers(0).open, true);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Pending);
+        })
+    }
+    #[test]
+    fn operator_should_rotate_itself() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::operator(), OPERATOR);
+            assert_noop!(
+                BridgeModule::set_operator(Origin::signed(V1), V1),
+                "Only bridge operator can call this function"
+            );
+            assert_noop!(
+                BridgeModule::set_direction_paused(Origin::ROOT, Direction::Deposit, true),
+                "bad origin: expected to be a signed origin"
+            );
+
+            assert_ok!(BridgeModule::set_operator(Origin::signed(OPERATOR), USER1));
+            assert_eq!(BridgeModule::operator(), USER1);
+
+            // the old operator is locked out
+            assert_noop!(
+                BridgeModule::set_direction_paused(
+                    Origin::signed(OPERATOR),
+                    Direction::Deposit,
+                    true
+                ),
+                "Only bridge operator can call this function"
+            );
+            assert_noop!(
+                BridgeModule::set_operator(Origin::signed(OPERATOR), OPERATOR),
+                "Only bridge operator can call this function"
+            );
+            assert_ok!(BridgeModule::set_direction_paused(
+                Origin::signed(USER1),
+                Direction::Deposit,
+                true
+            ));
+            assert!(BridgeModule::deposits_paused());
+        })
+    }
+    #[test]
+    fn mint_should_be_deferred_until_condition_holds() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            MINT_ALLOWED.with(|v| *v.borrow_mut() = false);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Approved);
+            assert_eq!(BridgeModule::deferred_mints(), vec![message_id]);
+            assert!(BridgeModule::failed_mints().is_empty());
+            assert_eq!(BridgeModule::failed_executions_count(), 0);
+            assert!(bridge_events().contains(&RawEvent::MintDeferred(message_id)));
+
+            assert_noop!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint condition is not met"
+            );
+
+            MINT_ALLOWED.with(|v| *v.borrow_mut() = true);
+            assert_ok!(BridgeModule::retry_mint(Origin::signed(USER1), message_id));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert!(BridgeModule::deferred_mints().is_empty());
+        })
+    }
+    #[test]
+    fn force_closed_mint_should_leave_the_retry_queue() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 500));
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(BridgeModule::failed_mints(), vec![message_id]);
+
+            assert_ok!(BridgeModule::force_close_transfer(
+                Origin::signed(V1),
+                message_id
+            ));
+            assert_ok!(BridgeModule::force_close_transfer(
+                Origin::signed(V3),
+                message_id
+            ));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Canceled);
+            assert!(BridgeModule::failed_mints().is_empty());
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 1000));
+            assert_noop!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint is not queued for retry"
+            );
+        })
+    }
+    #[test]
+    fn abandoned_deferred_mint_should_leave_the_queue() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            MINT_ALLOWED.with(|v| *v.borrow_mut() = false);
+            assert_ok!(BridgeModule::set_max_mint_retries(Origin::ROOT, 1));
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(BridgeModule::deferred_mints(), vec![message_id]);
+
+            MINT_ALLOWED.with(|v| *v.borrow_mut() = true);
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 500));
+            assert_ok!(BridgeModule::retry_mint(Origin::signed(USER1), message_id));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Canceled);
+            assert!(BridgeModule::deferred_mints().is_empty());
+        })
+    }
+    #[test]
+    fn locked_by_proposal_should_follow_withdraw() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let eth_unlock_tx = H256::from(ETH_UNLOCK_TX);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::locked_by_proposal(0), 0);
+
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(BridgeModule::locked_by_proposal(1), 0);
+
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 0);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 500);
+            assert_eq!(TokenModule::locked(USER2), 500);
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 500);
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 0);
+            assert_eq!(TokenModule::locked(USER2), 0);
+        })
+    }
+    #[test]
+    fn locked_by_proposal_should_be_zeroed_on_cancel() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 300));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 300);
+
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V1), sub_message_id));
+            assert_eq!(BridgeModule::locked_by_proposal(1), 0);
+            assert_eq!(TokenModule::locked(USER2), 0);
+        })
+    }
+    #[test]
+    fn indexing_hints_should_add_rich_events() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert!(bridge_events().contains(&RawEvent::Minted(message_id)));
+            assert!(!bridge_events().iter().any(|e| match e {
+                RawEvent::TransferIndexed(..) => true,
+                _ => false,
+            }));
+
+            assert_ok!(BridgeModule::set_indexing_hints(Origin::ROOT, true));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+
+            let events = bridge_events();
+            assert!(events.contains(&RawEvent::RelayMessage(sub_message_id)));
+            assert!(events.contains(&RawEvent::TransferIndexed(1, sub_message_id, 0, 500)));
+            assert!(events.contains(&RawEvent::TransferIndexed(1, sub_message_id, 1, 500)));
+            assert_eq!(BridgeModule::indexed_events_count(), 2);
+        })
+    }
+    #[test]
+    fn compute_transfer_hash_should_match_set_transfer() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::withdraw_nonce(USER2), 0);
+            let expected = BridgeModule::compute_transfer_hash(&USER2, eth_address, 100, 0);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 100));
+            assert_eq!(BridgeModule::message_id_by_transfer_id(1), expected);
+            assert_eq!(BridgeModule::withdraw_nonce(USER2), 1);
+
+            // the same request again gets a new id
+            let expected = BridgeModule::compute_transfer_hash(&USER2, eth_address, 100, 1);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 100));
+            assert_eq!(BridgeModule::message_id_by_transfer_id(2), expected);
+            assert_ne!(
+                BridgeModule::message_id_by_transfer_id(1),
+                BridgeModule::message_id_by_transfer_id(2)
+            );
+            assert_eq!(BridgeModule::messages(expected).amount, 100);
+        })
+    }
+    #[test]
+    fn no_validator_self_mint_should_reject_validator_recipients() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_no_validator_self_mint(Origin::ROOT, true));
+
+            assert_err!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    V2,
+                    1000
+                ),
+                "Can not mint to a validator during bootstrap"
+            );
+            assert_err!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER2, 500), (V1, 500)]
+                ),
+                "Can not mint to a validator during bootstrap"
+            );
+            assert_eq!(BridgeModule::rejected_mints_count(), 2);
+            assert!(!<TransferMessages<Test>>::exists(message_id));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn no_validator_self_mint_should_end_with_bootstrap() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_no_validator_self_mint(Origin::ROOT, true));
+            assert_ok!(BridgeModule::finalize_bootstrap(Origin::ROOT));
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                V2,
+                1000
+            ));
+        })
+    }
+    #[test]
+    fn validator_count_discrepancy_should_report_drift() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_eq!(BridgeModule::validator_count_discrepancy(), 0);
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
+            assert_eq!(BridgeModule::validator_count_discrepancy(), 0);
+
+            <ValidatorsCount<Test>>::put(4);
+            assert_eq!(BridgeModule::validator_count_discrepancy(), 2);
+            <ValidatorsCount<Test>>::put(2);
+            <Validators<Test>>::remove(V2);
+            assert_eq!(BridgeModule::validator_count_discrepancy(), 1);
+            <ValidatorsCount<Test>>::put(0);
+            assert_eq!(BridgeModule::validator_count_discrepancy(), -1);
+        })
+    }
+    #[test]
+    fn added_validator_should_wait_for_the_delay() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_add_delay(Origin::ROOT, 10));
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            let hash = BridgeModule::message_id_by_transfer_id(0);
+            assert!(!BridgeModule::validators(V4));
+            assert_eq!(BridgeModule::validators_count(), 3);
+            assert_eq!(BridgeModule::pending_validator_additions(), vec![(V4, 11)]);
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Approved);
+
+            BridgeModule::on_initialize(10);
+            assert!(!BridgeModule::validators(V4));
+            BridgeModule::on_initialize(11);
+            assert!(BridgeModule::validators(V4));
+            assert_eq!(BridgeModule::validators_count(), 4);
+            assert!(BridgeModule::pending_validator_additions().is_empty());
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Confirmed);
+        })
+    }
+    #[test]
+    fn validator_add_delay_past_the_last_block_should_saturate() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_add_delay(
+                Origin::ROOT,
+                u64::max_value()
+            ));
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            assert_eq!(
+                BridgeModule::pending_validator_additions(),
+                vec![(V4, u64::max_value())]
+            );
+            BridgeModule::on_initialize(1000);
+            assert!(!BridgeModule::validators(V4));
+        })
+    }
+    #[test]
+    fn existing_validator_should_not_be_added_again() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_noop!(
+                BridgeModule::add_validator(Origin::signed(V1), V2),
+                "Already a validator"
+            );
+
+            assert_ok!(BridgeModule::set_validator_add_delay(Origin::ROOT, 10));
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V2, V3, V4]));
+
+            let hash = BridgeModule::message_id_by_transfer_id(0);
+            BridgeModule::on_initialize(11);
+            assert_eq!(BridgeModule::validators_count(), 4);
+            assert_eq!(BridgeModule::total_validator_weight(), 4);
+            assert_eq!(BridgeModule::validator_history(hash).status, Status::Canceled);
+        })
+    }
+    #[test]
+    fn vetoed_validator_addition_should_be_dropped() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_add_delay(Origin::ROOT, 10));
+            system::Module::<Test>::set_block_number(1);
+            assert_noop!(
+                BridgeModule::veto_validator(Origin::signed(V1), V4),
+                "Validator addition is not pending"
+            );
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            assert_ok!(BridgeModule::veto_validator(Origin::signed(V3), V4));
+            assert_eq!(BridgeModule::pending_validator_additions().len(), 1);
+            assert_ok!(BridgeModule::veto_validator(Origin::signed(V1), V4));
+            assert!(BridgeModule::pending_validator_additions().is_empty());
+
+            BridgeModule::on_initialize(11);
+            assert!(!BridgeModule::validators(V4));
+            assert_eq!(BridgeModule::validators_count(), 3);
+
+            // can be proposed again
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_eq!(BridgeModule::transfers(2).open, true);
+        })
+    }
+    #[test]
+    fn failed_validator_activation_should_allow_proposing_again() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_add_delay(Origin::ROOT, 10));
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            let hash = BridgeModule::message_id_by_transfer_id(0);
+
+            // V4 can't afford the bond by the end of the window
+            assert_ok!(BridgeModule::set_validator_bond(Origin::ROOT, 200000));
+            BridgeModule::on_initialize(11);
+            assert!(!BridgeModule::validators(V4));
+            assert!(!<ValidatorHistory<Test>>::exists(hash));
+            assert!(!<TransferId<Test>>::exists(hash));
+
+            assert_ok!(BridgeModule::set_validator_bond(Origin::ROOT, 0));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_eq!(BridgeModule::transfers(1).open, true);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            BridgeModule::on_initialize(21);
+            assert!(BridgeModule::validators(V4));
+        })
+    }
+    #[test]
+    fn withdraw_should_keep_the_reserve_ratio() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_noop!(
+                BridgeModule::report_collateral(Origin::signed(USER2), 1000),
+                "Only bridge operator can call this function"
+            );
+            assert_ok!(BridgeModule::report_collateral(Origin::signed(OPERATOR), 1000));
+            assert_ok!(BridgeModule::set_min_reserve_ratio(Origin::ROOT, 50));
+
+            // 700 of supply would be left
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 300));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+
+            // approved 300 is already on its way out, 400 would leave 300 < 500
+            assert_err!(
+                BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 400),
+                "Withdraw would breach the reserve ratio"
+            );
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 200));
+        })
+    }
+    #[test]
+    fn approval_and_confirmation_should_have_separate_proposals() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let eth_unlock_tx = H256::from(ETH_UNLOCK_TX);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V3), sub_message_id));
+            assert_eq!(BridgeModule::confirmation_id_by_hash(sub_message_id), None);
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+            assert_eq!(BridgeModule::transfer_id_by_hash(sub_message_id), 1);
+            let confirmation_id = BridgeModule::confirmation_id_by_hash(sub_message_id);
+            assert_eq!(confirmation_id, Some(2));
+            assert_eq!(BridgeModule::message_id_by_transfer_id(2), sub_message_id);
+
+            let approval = BridgeModule::transfers(1);
+            assert_eq!((approval.open, approval.votes), (false, 2));
+            assert!(BridgeModule::has_signed(1, V3));
+            assert!(!BridgeModule::has_signed(1, V2));
+            let confirmation = BridgeModule::transfers(2);
+            assert_eq!((confirmation.open, confirmation.votes), (true, 1));
+            assert!(BridgeModule::has_signed(2, V2));
+            assert!(!BridgeModule::has_signed(2, V3));
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V3),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+            assert_eq!(BridgeModule::transfers(2).open, false);
+            assert_eq!(BridgeModule::transfers(1).votes, 2);
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+        })
+    }
+    #[test]
+    fn aborted_confirmation_should_get_a_new_proposal() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let eth_unlock_tx = H256::from(ETH_UNLOCK_TX);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), sub_message_id));
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+
+            // proposal 3 cancels the confirmation 2
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V2), sub_message_id));
+            assert_ok!(BridgeModule::cancel_burn_confirmation(Origin::signed(V3), sub_message_id));
+            assert_eq!(BridgeModule::transfers(2).open, false);
+            assert!(!BridgeModule::open_proposals().contains(&2));
+            assert_eq!(BridgeModule::confirmation_id_by_hash(sub_message_id), None);
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                eth_unlock_tx
+            ));
+            let confirmation_id = BridgeModule::confirmation_id_by_hash(sub_message_id);
+            assert_eq!(confirmation_id, Some(4));
+            assert_eq!(BridgeModule::transfers(4).votes, 1);
+        })
+    }
+    #[test]
+    fn account_position_should_bundle_balance_and_withdraws() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let eth_unlock_tx = H256::from(ETH_UNLOCK_TX);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // burned, approved and requested withdraws
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 100));
+            let burned = BridgeModule::message_id_by_transfer_id(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(Origin::signed(*validator), burned));
+            }
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::confirm_transfer(
+                    Origin::signed(*validator),
+                    burned,
+                    eth_unlock_tx
+                ));
+            }
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 200));
+            let approved = BridgeModule::message_id_by_transfer_id(3);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(Origin::signed(*validator), approved));
+            }
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 300));
+            let requested = BridgeModule::message_id_by_transfer_id(4);
+
+            assert_eq!(
+                BridgeModule::account_position(&USER2),
+                AccountPosition {
+                    balance: 900,
+                    locked: 200,
+                    pending_withdraws: vec![approved, requested],
+                    pending_amount: 500,
+                }
+            );
+            assert_eq!(BridgeModule::account_position(&USER1).pending_withdraws.len(), 0);
+        })
+    }
+    #[test]
+    fn mint_failing_past_max_retries_should_be_abandoned() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 500));
+            assert_ok!(BridgeModule::set_max_mint_retries(Origin::ROOT, 2));
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(BridgeModule::failed_mints(), vec![message_id]);
+
+            assert_err!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint would exceed the total supply cap"
+            );
+            assert_eq!(BridgeModule::retry_count(message_id), 1);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Approved);
+
+            // second failure reaches the cap
+            assert_ok!(BridgeModule::retry_mint(Origin::signed(USER1), message_id));
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Canceled);
+            assert!(BridgeModule::failed_mints().is_empty());
+            assert_eq!(BridgeModule::retry_count(message_id), 0);
+            assert!(bridge_events().contains(&RawEvent::MintPermanentlyFailed(message_id)));
+
+            assert_ok!(BridgeModule::set_max_total_supply(Origin::ROOT, 1000));
+            assert_noop!(
+                BridgeModule::retry_mint(Origin::signed(USER1), message_id),
+                "Mint is not queued for retry"
+            );
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+        })
+    }
+    #[test]
+    fn mint_source_should_resolve_completed_mints() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            let other_address = H160::from([9u8; 20]);
+            assert_eq!(BridgeModule::mint_source(message_id), None);
+
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            // not minted yet
+            assert_eq!(BridgeModule::mint_source(message_id), None);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V2),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::mint_source(message_id), Some(eth_address));
+
+            let split_id = H256::from([2u8; 32]);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint_split(
+                    Origin::signed(*validator),
+                    split_id,
+                    other_address,
+                    1000,
+                    vec![(USER1, 300), (USER2, 700)]
+                ));
+            }
+            assert_eq!(BridgeModule::mint_source(split_id), Some(other_address));
+            assert_eq!(BridgeModule::mint_sources(USER1), vec![other_address]);
+            assert_eq!(BridgeModule::mint_sources(USER2), vec![eth_address, other_address]);
+        })
+    }
+    #[test]
+    fn cancelled_withdraw_should_unlock_immediately_by_default() {
+        with_externalities(&mut new_test_ext(), || {
+            let sub_message_id = approved_withdraw_for_refund();
+
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V3), sub_message_id));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(BridgeModule::claimable_refund(USER2), 0);
+            assert_noop!(
+                BridgeModule::claim_refund(Origin::signed(USER2)),
+                "No refund to claim"
+            );
+        })
+    }
+    #[test]
+    fn cancelled_withdraw_should_be_claimable_when_enabled() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_claimable_refunds(Origin::ROOT, true));
+            let sub_message_id = approved_withdraw_for_refund();
+
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V3), sub_message_id));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Canceled);
+            assert_eq!(BridgeModule::pending_burn_total(), 0);
+            assert_eq!(TokenModule::locked(USER2), 500);
+            assert_eq!(BridgeModule::claimable_refund(USER2), 500);
+            assert_noop!(
+                BridgeModule::claim_refund(Origin::signed(USER1)),
+                "No refund to claim"
+            );
+
+            assert_ok!(BridgeModule::claim_refund(Origin::signed(USER2)));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(BridgeModule::claimable_refund(USER2), 0);
+        })
+    }
+
+    /// mints 1000 to USER2 and approves a withdraw of 500
+    fn approved_withdraw_for_refund() -> H256 {
+        let eth_message_id = H256::from(ETH_MESSAGE_ID);
+        let eth_address = H160::from(ETH_ADDRESS);
+        for validator in &[V1, V2] {
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(*validator),
+                eth_message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+        }
+        assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+        let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+        for validator in &[V1, V2] {
+            assert_ok!(BridgeModule::approve_transfer(
+                Origin::signed(*validator),
+                sub_message_id
+            ));
+        }
+        assert_eq!(TokenModule::locked(USER2), 500);
+        sub_message_id
+    }
+    #[test]
+    fn force_set_locked_should_work_only_while_paused() {
+        with_externalities(&mut new_test_ext(), || {
+            approved_withdraw_for_refund();
+            assert_noop!(
+                BridgeModule::force_set_locked(Origin::ROOT, USER2, 300),
+                "Bridge must be paused"
+            );
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_noop!(
+                BridgeModule::force_set_locked(Origin::signed(USER2), USER2, 0),
+                "bad origin: expected to be a root origin"
+            );
+
+            assert_noop!(
+                BridgeModule::force_set_locked(Origin::ROOT, USER2, 1001),
+                "Cannot lock more than the balance"
+            );
+            assert_ok!(BridgeModule::force_set_locked(Origin::ROOT, USER2, 300));
+            assert_eq!(TokenModule::locked(USER2), 300);
+            assert!(bridge_events().contains(&RawEvent::LockedBalanceCorrected(USER2, 500, 300)));
+            assert_ok!(BridgeModule::force_set_locked(Origin::ROOT, USER2, 0));
+            assert_eq!(TokenModule::locked(USER2), 0);
+        })
+    }
+    #[test]
+    fn proposal_ttl_past_the_last_block_should_not_set_a_deadline() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, u64::max_value()));
+
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_eq!(BridgeModule::proposal_deadline(0), None);
+            assert_eq!(BridgeModule::deadline_queue(), vec![]);
+            assert_eq!(BridgeModule::transfers(0).open, true);
+        })
+    }
+    #[test]
+    fn proposals_of_every_kind_should_expire_at_their_deadline() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, 10));
+
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            let validator_hash = BridgeModule::message_id_by_transfer_id(1);
+            let pause_hash = BridgeModule::message_id_by_transfer_id(2);
+            assert_eq!(BridgeModule::proposal_deadline(0), Some(11));
+            // bridge proposal gets more time
+            assert_ok!(BridgeModule::set_proposal_deadline(Origin::ROOT, 2, 20));
+            assert_eq!(BridgeModule::deadline_queue(), vec![(11, 0), (11, 1), (20, 2)]);
+
+            BridgeModule::on_finalize(10);
+            assert!(BridgeModule::transfers(0).open);
+            BridgeModule::on_finalize(11);
+            assert!(!BridgeModule::transfers(0).open);
+            assert!(!BridgeModule::transfers(1).open);
+            assert!(BridgeModule::transfers(2).open);
+            assert!(!<TransferMessages<Test>>::exists(message_id));
+            assert!(!<TransferId<Test>>::exists(message_id));
+            assert!(!<ValidatorHistory<Test>>::exists(validator_hash));
+            assert!(!<TransferId<Test>>::exists(validator_hash));
+
+            BridgeModule::on_finalize(20);
+            assert!(!BridgeModule::transfers(2).open);
+            assert!(!<BridgeMessages<Test>>::exists(pause_hash));
+            assert!(BridgeModule::deadline_queue().is_empty());
+            assert!(BridgeModule::open_proposals().is_empty());
+            assert!(bridge_events().contains(&RawEvent::ProposalExpired(0)));
+            assert!(bridge_events().contains(&RawEvent::ProposalExpired(1)));
+            assert!(bridge_events().contains(&RawEvent::ProposalExpired(2)));
+
+            // expired proposals can be submitted again
+            system::Module::<Test>::set_block_number(21);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_eq!(BridgeModule::transfer_id_by_hash(validator_hash), 3);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::transfer_id_by_hash(pause_hash), 4);
+        })
+    }
+    #[test]
+    fn expired_deposit_should_be_proposed_again() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, 10));
+
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                message_id,
+                eth_address,
+                USER2,
+                1000
+            ));
+            BridgeModule::on_finalize(11);
+            assert!(!<TransferMessages<Test>>::exists(message_id));
+            assert!(BridgeModule::transfers_with_status(Status::Pending).is_empty());
+
+            system::Module::<Test>::set_block_number(12);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(BridgeModule::transfer_id_by_hash(message_id), 1);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+        })
+    }
+    #[test]
+    fn proposals_reaching_quorum_should_ignore_their_deadline() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, 10));
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert_noop!(
+                BridgeModule::set_proposal_deadline(Origin::ROOT, 0, 20),
+                "Proposal is not open"
+            );
+
+            BridgeModule::on_finalize(11);
+            assert_eq!(BridgeModule::validators_count(), 4);
+            assert!(!bridge_events().contains(&RawEvent::ProposalExpired(0)));
+
+            // no deadline by default
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, 0));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V4));
+            assert_eq!(BridgeModule::proposal_deadline(1), None);
+        })
+    }
+    #[test]
+    fn recent_events_should_filter_by_block() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+
+            system::Module::<Test>::set_block_number(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            let minted = BridgeModule::recent_events(1);
+            assert_eq!(minted[0], EventSummary::StatusChanged(message_id, Status::Deposit));
+            assert_eq!(
+                minted.last(),
+                Some(&EventSummary::StatusChanged(message_id, Status::Confirmed))
+            );
+
+            system::Module::<Test>::set_block_number(5);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let withdraw_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(
+                BridgeModule::recent_events(5),
+                vec![EventSummary::StatusChanged(withdraw_id, Status::Withdraw)]
+            );
+            assert_eq!(BridgeModule::recent_events(1).len(), minted.len() + 1);
+            assert!(BridgeModule::recent_events(6).is_empty());
+        })
+    }
+    #[test]
+    fn recent_events_should_be_pruned_after_retention() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_recent_events_retention(Origin::ROOT, 10));
+
+            system::Module::<Test>::set_block_number(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            system::Module::<Test>::set_block_number(20);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let withdraw_id = BridgeModule::message_id_by_transfer_id(1);
+            assert_eq!(
+                <RecentEvents<Test>>::get(),
+                vec![(20, EventSummary::StatusChanged(withdraw_id, Status::Withdraw))]
+            );
+        })
+    }
+    #[test]
+    fn recent_events_should_be_kept_with_unbounded_retention() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_recent_events_retention(
+                Origin::ROOT,
+                u64::max_value()
+            ));
+
+            system::Module::<Test>::set_block_number(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            system::Module::<Test>::set_block_number(20);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let events = <RecentEvents<Test>>::get();
+            assert_eq!(events.first().map(|(block, _)| *block), Some(1));
+            assert_eq!(events.last().map(|(block, _)| *block), Some(20));
+        })
+    }
+    #[test]
+    fn mint_and_pause_authorities_should_be_separate() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_noop!(
+                BridgeModule::set_pause_validators(Origin::ROOT, vec![V4]),
+                "Authority members must be validators"
+            );
+            assert_ok!(BridgeModule::set_mint_validators(Origin::ROOT, vec![V1, V2]));
+            assert_ok!(BridgeModule::set_pause_validators(Origin::ROOT, vec![V3]));
+
+            assert_noop!(
+                BridgeModule::multi_signed_mint(
+                    Origin::signed(V3),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ),
+                "Only mint validators can call this function"
+            );
+            assert_noop!(
+                BridgeModule::pause_bridge(Origin::signed(V1)),
+                "Only pause validators can call this function"
+            );
+
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+
+            // the pause set is a quorum on its own
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V3)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+        })
+    }
+    #[test]
+    fn empty_authority_sets_should_fall_back_to_all_validators() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_pause_validators(Origin::ROOT, vec![V3]));
+            assert_ok!(BridgeModule::set_pause_validators(Origin::ROOT, vec![]));
+
+            for validator in &[V3, V1] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
+            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::bridge_is_operational(), false);
+        })
+    }
+    #[test]
+    fn is_locked_should_follow_the_withdraw_lifecycle() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    eth_message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            // deposits never lock
+            assert!(!BridgeModule::is_locked(eth_message_id));
+
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            assert!(!BridgeModule::is_locked(sub_message_id));
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id
+                ));
+            }
+            assert!(BridgeModule::is_locked(sub_message_id));
+
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V1),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert!(BridgeModule::is_locked(sub_message_id));
+            assert_ok!(BridgeModule::confirm_transfer(
+                Origin::signed(V2),
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
+            ));
+            assert_eq!(BridgeModule::messages(sub_message_id).phase, WithdrawPhase::Burned);
+            assert!(!BridgeModule::is_locked(sub_message_id));
+        })
+    }
+    #[test]
+    fn is_locked_should_be_false_after_cancel() {
+        with_externalities(&mut new_test_ext(), || {
+            let sub_message_id = approved_withdraw_for_refund();
+            assert!(BridgeModule::is_locked(sub_message_id));
+
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V3), sub_message_id));
+            assert!(!BridgeModule::is_locked(sub_message_id));
+            assert!(!BridgeModule::is_locked(H256::from([7u8; 32])));
+        })
+    }
+    #[test]
+    fn reconcile_all_should_execute_proposals_reaching_quorum() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_min_absolute_votes(Origin::ROOT, 3));
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert!(BridgeModule::transfers(0).open);
+
+            assert_ok!(BridgeModule::set_min_absolute_votes(Origin::ROOT, 2));
+            assert_ok!(BridgeModule::reconcile_all(Origin::ROOT, 10));
+            assert!(!BridgeModule::transfers(0).open);
+            assert_eq!(BridgeModule::messages(message_id).status, Status::Confirmed);
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(BridgeModule::reconcile_cursor(), 0);
+        })
+    }
+    #[test]
+    fn reconcile_all_should_cancel_dead_proposals_in_batches() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(TokenModule::_mint(USER1, 1000));
+            assert_ok!(TokenModule::_mint(USER2, 1000));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER1), eth_address, 500));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let first = BridgeModule::message_id_by_transfer_id(0);
+            let second = BridgeModule::message_id_by_transfer_id(1);
+            assert_ok!(BridgeModule::approve_transfer(Origin::signed(V1), second));
+
+            // three validators can't give four signatures
+            assert_ok!(BridgeModule::set_min_absolute_votes(Origin::ROOT, 4));
+            assert_ok!(BridgeModule::reconcile_all(Origin::ROOT, 1));
+            assert_eq!(BridgeModule::messages(second).status, Status::Canceled);
+            assert_eq!(BridgeModule::messages(first).status, Status::Withdraw);
+            assert_eq!(BridgeModule::reconcile_cursor(), 1);
+
+            assert_ok!(BridgeModule::reconcile_all(Origin::ROOT, 1));
+            assert_eq!(BridgeModule::messages(first).status, Status::Canceled);
+            assert_eq!(BridgeModule::reconcile_cursor(), 0);
+            assert!(BridgeModule::open_proposals().is_empty());
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::locked(USER2), 0);
+        })
+    }
+    #[test]
+    fn validator_should_be_added_removed_and_added_again() {
+        with_externalities(&mut new_test_ext(), || {
+            for _ in 0..2 {
+                assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+                assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+                assert!(BridgeModule::validators(V4));
+                assert_eq!(BridgeModule::validators_count(), 4);
+
+                assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V4));
+                assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V4));
+                assert_ok!(BridgeModule::remove_validator(Origin::signed(V3), V4));
+                assert!(!BridgeModule::validators(V4));
+                assert_eq!(BridgeModule::validators_count(), 3);
+            }
+            // every round got fresh proposals
+            assert_eq!(BridgeModule::bridge_transfers_count(), 4);
+            let add_hash = BridgeModule::message_id_by_transfer_id(0);
+            assert_eq!(add_hash, BridgeModule::message_id_by_transfer_id(2));
+            assert!(!<TransferId<Test>>::exists(add_hash));
+            assert!(!<ValidatorHistory<Test>>::exists(add_hash));
+        })
+    }
+    #[test]
+    fn participation_rate_should_count_signed_proposals_in_window() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::participation_rate(&V1, 10), 0);
+
+            for id in 1..5u8 {
+                let message_id = H256::from([id; 32]);
+                for validator in &[V1, V2] {
+                    assert_ok!(BridgeModule::multi_signed_mint(
+                        Origin::signed(*validator),
+                        message_id,
+                        eth_address,
+                        USER2,
+                        1000
+                    ));
+                }
+            }
+            // V2 skips the last one
+            assert_ok!(BridgeModule::multi_signed_mint(
+                Origin::signed(V1),
+                H256::from([5u8; 32]),
+                eth_address,
+                USER2,
+                1000
+            ));
+
+            assert_eq!(BridgeModule::participation_rate(&V1, 5), 100);
+            assert_eq!(BridgeModule::participation_rate(&V2, 5), 80);
+            assert_eq!(BridgeModule::participation_rate(&V2, 1), 0);
+            assert_eq!(BridgeModule::participation_rate(&V3, 100), 0);
+            // window longer than the history counts every proposal
+            assert_eq!(BridgeModule::participation_rate(&V1, 100), 100);
+        })
+    }
+    #[test]
+    fn escrowed_mint_should_be_spendable_only_after_claim() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_mint_escrow(Origin::ROOT, true));
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(USER2), 1000);
+            assert_eq!(TokenModule::locked(USER2), 1000);
+            assert_eq!(BridgeModule::escrowed_mint((message_id, USER2)), 1000);
+            assert_noop!(
+                TokenModule::transfer(Origin::signed(USER2), USER1, 100),
+                "not enough because of locked funds"
+            );
+            assert_noop!(
+                BridgeModule::claim_minted(Origin::signed(USER1), message_id),
+                "No escrowed funds to claim"
+            );
+
+            assert_ok!(BridgeModule::claim_minted(Origin::signed(USER2), message_id));
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(BridgeModule::escrowed_mint((message_id, USER2)), 0);
+            assert_ok!(TokenModule::transfer(Origin::signed(USER2), USER1, 100));
+            assert_eq!(TokenModule::balance_of(USER1), 100);
+            assert_noop!(
+                BridgeModule::claim_minted(Origin::signed(USER2), message_id),
+                "No escrowed funds to claim"
+            );
+        })
+    }
+    #[test]
+    fn escrowed_mint_should_not_be_written_if_the_lock_overflows() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_mint_escrow(Origin::ROOT, true));
+            assert_ok!(TokenModule::lock(USER2, u64::max_value() - 500));
+            let total_supply = TokenModule::total_supply();
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(BridgeModule::failed_mints(), vec![message_id]);
+            assert_eq!(TokenModule::balance_of(USER2), 0);
+            assert_eq!(TokenModule::total_supply(), total_supply);
+            assert_eq!(TokenModule::locked(USER2), u64::max_value() - 500);
+            assert_eq!(BridgeModule::escrowed_mint((message_id, USER2)), 0);
+        })
+    }
+    #[test]
+    fn mint_without_escrow_should_be_spendable_at_once() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    USER2,
+                    1000
+                ));
+            }
+            assert_eq!(TokenModule::locked(USER2), 0);
+            assert_eq!(BridgeModule::escrowed_mint((message_id, USER2)), 0);
+            assert_ok!(TokenModule::transfer(Origin::signed(USER2), USER1, 100));
+        })
+    }
+    #[test]
+    fn mint_and_burn_events_should_report_resulting_balances() {
+        with_externalities(&mut new_test_ext(), || {
+            let message_id = H256::from(ETH_MESSAGE_ID);
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(TokenModule::_mint(USER1, 200));
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::multi_signed_mint_split(
+                    Origin::signed(*validator),
+                    message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER1, 300), (USER2, 700)]
+                ));
+            }
+            let events = bridge_events();
+            assert!(events.contains(&RawEvent::MintedBalance(
+                message_id,
+                USER1,
+                200,
+                TokenModule::balance_of(USER1)
+            )));
+            assert!(events.contains(&RawEvent::MintedBalance(
+                message_id,
+                USER2,
+                0,
+                TokenModule::balance_of(USER2)
+            )));
+            assert_eq!(TokenModule::balance_of(USER1), 500);
+
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::approve_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id
+                ));
+            }
+            for validator in &[V1, V2] {
+                assert_ok!(BridgeModule::confirm_transfer(
+                    Origin::signed(*validator),
+                    sub_message_id,
+                    H256::from(ETH_UNLOCK_TX)
+                ));
+            }
+            assert_eq!(TokenModule::balance_of(USER2), 200);
+            assert!(bridge_events().contains(&RawEvent::BurnedBalance(
+                sub_message_id,
+                USER2,
+                700,
+                TokenModule::balance_of(USER2)
+            )));
+        })
+    }
+    #[test]
+    fn voluntary_exit_should_refund_the_validator_bond() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_bond(Origin::ROOT, 1000));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+            assert!(BridgeModule::validators(V4));
+            assert_eq!(balances::Module::<Test>::reserved_balance(V4), 1000);
+            assert_eq!(balances::Module::<Test>::free_balance(V4), 99000);
+            assert_eq!(BridgeModule::bonded_amount(V4), 1000);
+
+            assert_ok!(BridgeModule::voluntary_exit(Origin::signed(V4)));
+            assert!(!BridgeModule::validators(V4));
+            assert_eq!(BridgeModule::validators_count(), 3);
+            assert_eq!(balances::Module::<Test>::reserved_balance(V4), 0);
+            assert_eq!(balances::Module::<Test>::free_balance(V4), 100000);
+            assert_noop!(
+                BridgeModule::voluntary_exit(Origin::signed(V4)),
+                "Only validators can call this function"
+            );
+        })
+    }
+    #[test]
+    fn removed_validator_should_lose_the_bond() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(BridgeModule::set_validator_bond(Origin::ROOT, 1000));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
+
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V4));
+            // can't run away from a removal vote
+            assert_noop!(
+                BridgeModule::voluntary_exit(Origin::signed(V4)),
+                "Validator removal is being voted"
+            );
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V4));
+            assert_ok!(BridgeModule::remove_validator(Origin::signed(V3), V4));
+            assert!(!BridgeModule::validators(V4));
+            assert_eq!(balances::Module::<Test>::reserved_balance(V4), 0);
+            assert_eq!(balances::Module::<Test>::free_balance(V4), 99000);
+            assert_eq!(BridgeModule::bonded_amount(V4), 0);
+        })
+    }
+    #[test]
+    fn validator_without_funds_for_the_bond_should_not_be_added() {
+        with_externalities(&mut new_test_ext(), || {
+            let poor_account = 99;
+            assert_ok!(BridgeModule::set_validator_bond(Origin::ROOT, 1000));
+            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), poor_account));
+            assert_err!(
+                BridgeModule::add_validator(Origin::signed(V2), poor_account),
+                "Not enough balance for the validator bond"
+            );
+            assert!(!BridgeModule::validators(poor_account));
+            assert_eq!(BridgeModule::validators_count(), 3);
+        })
     }
-
     #[test]
-    fn token_eth2sub_mint_works() {
+    fn proposal_signers_should_list_validators_who_signed() {
         with_externalities(&mut new_test_ext(), || {
             let message_id = H256::from(ETH_MESSAGE_ID);
             let eth_address = H160::from(ETH_ADDRESS);
+            assert_eq!(BridgeModule::proposal_signers(0), Vec::<u64>::new());
 
-            //substrate <----- ETH
             assert_ok!(BridgeModule::multi_signed_mint(
-                Origin::signed(V2),
+                Origin::signed(V3),
                 message_id,
                 eth_address,
                 USER2,
                 1000
             ));
-            let mut message = BridgeModule::messages(message_id);
-            assert_eq!(message.status, Status::Pending);
-
+            assert_eq!(BridgeModule::proposal_signers(0), vec![V3]);
             assert_ok!(BridgeModule::multi_signed_mint(
                 Origin::signed(V1),
                 message_id,
@@ -612,283 +9010,152 @@ mod tests {
                 USER2,
                 1000
             ));
-            message = BridgeModule::messages(message_id);
-            assert_eq!(message.status, Status::Confirmed);
-
-            let transfer = BridgeModule::transfers(0);
-            assert_eq!(transfer.open, false);
+            // executed proposal keeps its roster
+            assert!(!BridgeModule::transfers(0).open);
+            assert_eq!(BridgeModule::proposal_signers(0), vec![V1, V3]);
 
-            assert_eq!(TokenModule::balance_of(USER2), 1000);
-            assert_eq!(TokenModule::total_supply(), 1000);
+            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
+            assert_eq!(BridgeModule::proposal_signers(1), vec![V2]);
         })
     }
     #[test]
-    fn token_eth2sub_closed_transfer_fail() {
+    fn mint_to_reserved_account_should_be_rejected() {
         with_externalities(&mut new_test_ext(), || {
             let message_id = H256::from(ETH_MESSAGE_ID);
             let eth_address = H160::from(ETH_ADDRESS);
-
-            //substrate <----- ETH
-            assert_ok!(BridgeModule::multi_signed_mint(
-                Origin::signed(V2),
-                message_id,
-                eth_address,
-                USER2,
-                1000
-            ));
-            assert_ok!(BridgeModule::multi_signed_mint(
-                Origin::signed(V1),
-                message_id,
-                eth_address,
-                USER2,
-                1000
-            ));
-            assert_noop!(
+            let zero_account = 0;
+            assert_err!(
                 BridgeModule::multi_signed_mint(
-                    Origin::signed(V3),
+                    Origin::signed(V1),
                     message_id,
                     eth_address,
-                    USER2,
+                    zero_account,
                     1000
                 ),
-                "This transfer is not open"
+                "Cannot mint to reserved account"
             );
-            assert_eq!(TokenModule::balance_of(USER2), 1000);
-            assert_eq!(TokenModule::total_supply(), 1000);
-            let transfer = BridgeModule::transfers(0);
-            assert_eq!(transfer.open, false);
-
-            let message = BridgeModule::messages(message_id);
-            assert_eq!(message.status, Status::Confirmed);
-        })
-    }
 
-    #[test]
-    fn token_sub2eth_burn_works() {
-        with_externalities(&mut new_test_ext(), || {
-            let eth_message_id = H256::from(ETH_MESSAGE_ID);
-            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_reserved_mint_recipients(Origin::ROOT, vec![USER1]));
+            assert_err!(
+                BridgeModule::multi_signed_mint_split(
+                    Origin::signed(V1),
+                    message_id,
+                    eth_address,
+                    1000,
+                    vec![(USER2, 500), (USER1, 500)]
+                ),
+                "Cannot mint to reserved account"
+            );
+            assert_err!(
+                BridgeModule::batch_multi_signed_mint(
+                    Origin::signed(V1),
+                    vec![(message_id, eth_address, USER1, 1000)]
+                ),
+                "Cannot mint to reserved account"
+            );
+            assert_eq!(BridgeModule::rejected_mints_count(), 3);
+            assert_eq!(BridgeModule::bridge_transfers_count(), 0);
 
-            //substrate <----- ETH
-            assert_ok!(BridgeModule::multi_signed_mint(
-                Origin::signed(V2),
-                eth_message_id,
-                eth_address,
-                USER2,
-                1000
-            ));
             assert_ok!(BridgeModule::multi_signed_mint(
                 Origin::signed(V1),
-                eth_message_id,
+                message_id,
                 eth_address,
                 USER2,
                 1000
             ));
+        })
+    }
+    #[test]
+    fn reported_transfer_closed_without_fraud_verdict_should_not_pay() {
+        with_externalities(&mut new_test_ext(), || {
+            let eth_address = H160::from(ETH_ADDRESS);
+            assert_ok!(BridgeModule::set_fee_account(Origin::ROOT, FEE_ACCOUNT));
+            assert_ok!(BridgeModule::set_fraud_bounty(Origin::ROOT, 50));
+            assert_ok!(BridgeModule::set_proposal_ttl(Origin::ROOT, 10));
+            assert_ok!(TokenModule::_mint(FEE_ACCOUNT, 100));
+            assert_ok!(TokenModule::_mint(USER2, 1000));
 
-            //substrate ----> ETH
-            assert_ok!(BridgeModule::set_transfer(
-                Origin::signed(USER2),
-                eth_address,
-                500
-            ));
-            //RelayMessage(message_id) event emitted
-
-            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
-            let get_message = || BridgeModule::messages(sub_message_id);
-
-            let mut message = get_message();
-            assert_eq!(message.status, Status::Withdraw);
+            system::Module::<Test>::set_block_number(1);
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            assert_ok!(BridgeModule::set_transfer(Origin::signed(USER2), eth_address, 500));
+            let expiring = BridgeModule::message_id_by_transfer_id(0);
+            let cancelled = BridgeModule::message_id_by_transfer_id(1);
+            for message_id in &[expiring, cancelled] {
+                assert_ok!(BridgeModule::report_fraud(
+                    Origin::signed(USER1),
+                    *message_id,
+                    vec![]
+                ));
+            }
 
-            //approval
-            assert_eq!(TokenModule::locked(USER2), 0);
-            assert_ok!(BridgeModule::approve_transfer(
+            assert_ok!(BridgeModule::cancel_transfer(Origin::signed(V3), cancelled));
+            BridgeModule::on_finalize(11);
+            assert_eq!(BridgeModule::messages(expiring).status, Status::Canceled);
+            assert_eq!(TokenModule::balance_of(USER1), 0);
+            assert_eq!(TokenModule::balance_of(FEE_ACCOUNT), 100);
+            assert_noop!(
+                BridgeModule::confirm_fraud(Origin::signed(V1), expiring),
+                "Transfer is already cancelled"
+            );
+        })
+    }
+    #[test]
+    fn prune_finalized_should_keep_withdraw_being_confirmed() {
+        with_externalities(&mut new_test_ext(), || {
+            let sub_message_id = approved_withdraw_for_refund();
+            assert_ok!(BridgeModule::confirm_transfer(
                 Origin::signed(V1),
-                sub_message_id
-            ));
-            assert_ok!(BridgeModule::approve_transfer(
-                Origin::signed(V2),
-                sub_message_id
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
             ));
+            assert_eq!(BridgeModule::messages(sub_message_id).status, Status::Confirmed);
+            let confirmation_id = BridgeModule::confirmation_id_by_hash(sub_message_id).unwrap();
+            system::Module::<Test>::set_block_number(5);
 
-            message = get_message();
-            assert_eq!(message.status, Status::Approved);
-
-            // at this point transfer is in Approved status and are waiting for confirmation
-            // from ethereum side to burn. Funds are locked.
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 10));
+            assert!(<TransferMessages<Test>>::exists(sub_message_id));
+            assert!(<BridgeTransfers<Test>>::exists(1));
+            assert!(BridgeModule::transfers(confirmation_id).open);
+            assert_eq!(BridgeModule::pending_burn_total(), 500);
             assert_eq!(TokenModule::locked(USER2), 500);
-            assert_eq!(TokenModule::balance_of(USER2), 1000);
-            // once it happends, validators call confirm_transfer
 
+            // burn finalizes it
             assert_ok!(BridgeModule::confirm_transfer(
                 Origin::signed(V2),
-                sub_message_id
-            ));
-
-            message = get_message();
-            let transfer = BridgeModule::transfers(1);
-            assert_eq!(message.status, Status::Confirmed);
-            assert_eq!(transfer.open, true);
-            assert_ok!(BridgeModule::confirm_transfer(
-                Origin::signed(V1),
-                sub_message_id
+                sub_message_id,
+                H256::from(ETH_UNLOCK_TX)
             ));
-            // assert_ok!(BridgeModule::confirm_transfer(Origin::signed(USER1), sub_message_id));
-            //Burned(Hash, AccountId, H160, u64) event emitted
-
-            assert_eq!(TokenModule::balance_of(USER2), 500);
-            assert_eq!(TokenModule::total_supply(), 500);
+            assert_ok!(BridgeModule::prune_finalized(Origin::ROOT, 5, 10));
+            assert!(!<TransferMessages<Test>>::exists(sub_message_id));
         })
     }
+
     #[test]
-    fn token_sub2eth_burn_fail_skip_approval() {
+    fn proposal_signers_should_survive_validator_replacement() {
         with_externalities(&mut new_test_ext(), || {
-            let eth_message_id = H256::from(ETH_MESSAGE_ID);
+            let message_id = H256::from(ETH_MESSAGE_ID);
             let eth_address = H160::from(ETH_ADDRESS);
 
-            //substrate <----- ETH
             assert_ok!(BridgeModule::multi_signed_mint(
                 Origin::signed(V2),
-                eth_message_id,
+                message_id,
                 eth_address,
                 USER2,
                 1000
             ));
             assert_ok!(BridgeModule::multi_signed_mint(
                 Origin::signed(V1),
-                eth_message_id,
+                message_id,
                 eth_address,
                 USER2,
                 1000
             ));
-            assert_eq!(TokenModule::balance_of(USER2), 1000);
-            assert_eq!(TokenModule::total_supply(), 1000);
-
-            //substrate ----> ETH
-            assert_ok!(BridgeModule::set_transfer(
-                Origin::signed(USER2),
-                eth_address,
-                500
-            ));
-            //RelayMessage(message_id) event emitted
-
-            let sub_message_id = BridgeModule::message_id_by_transfer_id(1);
-            let message = BridgeModule::messages(sub_message_id);
-            assert_eq!(message.status, Status::Withdraw);
-
-            assert_eq!(TokenModule::locked(USER2), 0);
-            // lets say validators blacked out and we
-            // try to confirm without approval anyway
-            assert_noop!(
-                BridgeModule::confirm_transfer(Origin::signed(V1), sub_message_id),
-                "This transfer must be approved first."
-            );
-        })
-    }
-    #[test]
-    fn add_validator_should_work() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_ok!(BridgeModule::add_validator(Origin::signed(V2), V4));
-            let id = BridgeModule::message_id_by_transfer_id(0);
-            let mut message = BridgeModule::validator_history(id);
-            assert_eq!(message.status, Status::Pending);
-
-            assert_ok!(BridgeModule::add_validator(Origin::signed(V1), V4));
-            message = BridgeModule::validator_history(id);
-            assert_eq!(message.status, Status::Confirmed);
-            assert_eq!(BridgeModule::validators_count(), 4);
-        })
-    }
-    #[test]
-    fn remove_validator_should_work() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
-            let id = BridgeModule::message_id_by_transfer_id(0);
-            let mut message = BridgeModule::validator_history(id);
-            assert_eq!(message.status, Status::Pending);
-
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
-            message = BridgeModule::validator_history(id);
-            assert_eq!(message.status, Status::Revoked);
-            assert_eq!(BridgeModule::validators_count(), 2);
-        })
-    }
-    #[test]
-    fn remove_last_validator_should_fail() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V3));
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V3));
-            assert_eq!(BridgeModule::validators_count(), 2);
-
-            //TODO: deal with two validators corner case
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V1), V2));
-            assert_ok!(BridgeModule::remove_validator(Origin::signed(V2), V2));
-            // ^ this guy probably will not sign his removal ^
-
-            assert_eq!(BridgeModule::validators_count(), 1);
-            // TODO: fails through different hashes
-            // assert_ok fails with corect error but the noop below fails with different hashes
-            // assert_noop!(BridgeModule::remove_validator(Origin::signed(V1), V1), "Cant remove last validator");
-        })
-    }
-    #[test]
-    fn pause_the_bridge_should_work() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
-
-            assert_eq!(BridgeModule::bridge_transfers_count(), 1);
-            assert_eq!(BridgeModule::bridge_is_operational(), true);
-            let id = BridgeModule::message_id_by_transfer_id(0);
-            let mut message = BridgeModule::bridge_messages(id);
-            assert_eq!(message.status, Status::Pending);
+            assert!(!BridgeModule::transfers(0).open);
 
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
-            assert_eq!(BridgeModule::bridge_is_operational(), false);
-            message = BridgeModule::bridge_messages(id);
-            assert_eq!(message.status, Status::Confirmed);
-        })
-    }
-    #[test]
-    fn extrinsics_restricted_should_fail() {
-        with_externalities(&mut new_test_ext(), || {
-            let eth_message_id = H256::from(ETH_MESSAGE_ID);
-            let eth_address = H160::from(ETH_ADDRESS);
-
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
-
-            // substrate <-- Ethereum
-            assert_noop!(
-                BridgeModule::multi_signed_mint(
-                    Origin::signed(V2),
-                    eth_message_id,
-                    eth_address,
-                    USER2,
-                    1000
-                ),
-                "Bridge is not operational"
-            );
-        })
-    }
-    #[test]
-    fn double_pause_should_fail() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_eq!(BridgeModule::bridge_is_operational(), true);
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
-            assert_eq!(BridgeModule::bridge_is_operational(), false);
-            assert_noop!(BridgeModule::pause_bridge(Origin::signed(V1)), "Bridge is not operational already");
-        })
-    }
-    #[test]
-    fn pause_and_resume_the_bridge_should_work() {
-        with_externalities(&mut new_test_ext(), || {
-            assert_eq!(BridgeModule::bridge_is_operational(), true);
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V2)));
-            assert_ok!(BridgeModule::pause_bridge(Origin::signed(V1)));
-            assert_eq!(BridgeModule::bridge_is_operational(), false);
-            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V1)));
-            assert_ok!(BridgeModule::resume_bridge(Origin::signed(V2)));
-            assert_eq!(BridgeModule::bridge_is_operational(), true);
+            assert_ok!(BridgeModule::set_validators(Origin::ROOT, vec![V1, V3, V4]));
+            assert_eq!(BridgeModule::proposal_signers(0), vec![V1, V2]);
+            assert!(BridgeModule::has_signed(0, V2));
+            assert!(!BridgeModule::has_signed(0, V4));
         })
     }
 }
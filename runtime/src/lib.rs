@@ -278,6 +278,9 @@ impl token::Trait for Runtime {
 
 impl bridge::Trait for Runtime {
     type Event = Event;
+    type OnMint = ();
+    type OnBurn = ();
+    type MintCondition = ();
 }
 
 construct_runtime!(
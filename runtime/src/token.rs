@@ -102,14 +102,15 @@ impl<T: Trait> Module<T> {
             "Cannot burn more than total supply"
         );
 
-        let free_balance = <Balance<T>>::get(&from) - <Locked<T>>::get(&from);
+        let balance = <Balance<T>>::get(&from);
+        let free_balance = balance - <Locked<T>>::get(&from);
         ensure!(
             free_balance > TokenBalance::zero(),
             "Cannot burn with zero balance"
         );
         ensure!(free_balance >= amount, "not enough because of locked funds");
 
-        let next_balance = free_balance
+        let next_balance = balance
             .checked_sub(amount)
             .ok_or("underflow subtracting from balance burn")?;
         let next_total = Self::total_supply()
@@ -138,7 +139,7 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    fn make_transfer(from: T::AccountId, to: T::AccountId, amount: TokenBalance) -> Result {
+    pub fn make_transfer(from: T::AccountId, to: T::AccountId, amount: TokenBalance) -> Result {
         let from_balance = <Balance<T>>::get(&from);
         ensure!(from_balance >= amount, "user does not have enough tokens");
         let free_balance = <Balance<T>>::get(&from) - <Locked<T>>::get(&from);
@@ -152,7 +153,22 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
     pub fn lock(account: T::AccountId, amount: TokenBalance) -> Result {
-        <Locked<T>>::insert(account.clone(), amount);
+        let locked = <Locked<T>>::get(&account)
+            .checked_add(amount)
+            .ok_or("overflow while locking")?;
+        <Locked<T>>::insert(account, locked);
+
+        Ok(())
+    }
+    pub fn set_locked(account: &T::AccountId, amount: TokenBalance) -> Result {
+        ensure!(
+            amount <= Self::balance_of(account),
+            "Cannot lock more than the balance"
+        );
+        match amount {
+            0 => <Locked<T>>::remove(account),
+            _ => <Locked<T>>::insert(account.clone(), amount),
+        }
 
         Ok(())
     }
@@ -314,4 +330,24 @@ mod tests {
             assert_eq!(TokenModule::balance_of(USER2), 0);
         })
     }
+    #[test]
+    fn token_lock_accumulates() {
+        with_externalities(&mut new_test_ext(), || {
+            assert_ok!(TokenModule::_mint(USER2, 1000));
+            assert_ok!(TokenModule::lock(USER2, 300));
+            assert_ok!(TokenModule::lock(USER2, 200));
+            assert_eq!(TokenModule::locked(USER2), 500);
+
+            // locked funds stay on the balance when the rest is burned
+            assert_ok!(TokenModule::_burn(USER2, 500));
+            assert_eq!(TokenModule::balance_of(USER2), 500);
+            assert_noop!(
+                TokenModule::_burn(USER2, 1),
+                "Cannot burn with zero balance"
+            );
+
+            assert_ok!(TokenModule::unlock(&USER2, 300));
+            assert_eq!(TokenModule::locked(USER2), 200);
+        })
+    }
 }
@@ -1,5 +1,6 @@
 use parity_codec::{Decode, Encode};
 use primitives::H160;
+use rstd::prelude::Vec;
 
 
 pub type MemberId = u64;
@@ -17,8 +18,12 @@ pub struct BridgeTransfer<Hash> {
     pub transfer_id: ProposalId,
     pub message_id: Hash,
     pub open: bool,
+    // sum of signers' weights
     pub votes: MemberId,
+    pub signatures: MemberId,
     pub kind: Kind,
+    // tokens locked for this withdraw until burn or cancel
+    pub locked: TokenBalance,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq)]
@@ -35,6 +40,13 @@ pub enum Status {
     Approved,
     Canceled,
     Confirmed,
+    SetMaxValidators,
+    CancelMint,
+    ForceClose,
+    CancelBurnConfirmation,
+    VetoValidator,
+    ConfirmFraud,
+    DropPreregisteredMint,
 }
 
 #[derive(Encode, Decode, Clone)]
@@ -43,6 +55,72 @@ pub enum Kind {
     Transfer,
     Validator,
     Bridge,
+    Limits,
+    Cancel,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum Direction {
+    Deposit,
+    Withdraw,
+}
+
+// steps of a withdraw, deposits stay in Requested
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum WithdrawPhase {
+    Requested,
+    Approved,
+    Confirming,
+    Burned,
+    Canceled,
+}
+
+// fee and limits which apply to a transfer right now
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct EffectiveParams {
+    pub fee: TokenBalance,
+    pub min_amount: TokenBalance,
+    pub supply_headroom: TokenBalance,
+    pub bootstrapping: bool,
+    pub allowed: bool,
+}
+
+// wallet view of an account, pending withdraws aren't burned or cancelled yet
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountPosition<Hash> {
+    pub balance: TokenBalance,
+    pub locked: TokenBalance,
+    pub pending_withdraws: Vec<Hash>,
+    pub pending_amount: TokenBalance,
+}
+
+// transfer state transition kept for relayers resyncing after downtime
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum EventSummary<Hash> {
+    StatusChanged(Hash, Status),
+    PhaseChanged(Hash, WithdrawPhase),
+}
+
+// r, s and recovery id of a secp256k1 signature made on ethereum side
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct EthSignature(pub [u8; 32], pub [u8; 32], pub u8);
+
+// quorum parameters shared by all proposal kinds
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct QuorumConfig {
+    pub threshold_percent: u32,
+    pub min_absolute_votes: MemberId,
+    pub min_validators: u32,
+    pub max_validators: u32,
+    pub validators_count: u32,
+    pub total_weight: u32,
 }
 
 #[derive(Encode, Decode, Clone)]
@@ -54,6 +132,7 @@ pub struct TransferMessage<AccountId, Hash> {
     pub amount: TokenBalance,
     pub status: Status,
     pub action: Status,
+    pub phase: WithdrawPhase,
 }
 
 #[derive(Encode, Decode, Clone)]
@@ -74,6 +153,26 @@ pub struct BridgeMessage<AccountId, Hash> {
     pub status: Status,
 }
 
+#[derive(Encode, Decode, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct LimitMessage<AccountId, Hash> {
+    pub message_id: Hash,
+    pub account: AccountId,
+    pub limit: u32,
+    pub action: Status,
+    pub status: Status,
+}
+
+#[derive(Encode, Decode, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CancelMessage<AccountId, Hash> {
+    pub message_id: Hash,
+    pub transfer_hash: Hash,
+    pub account: AccountId,
+    pub action: Status,
+    pub status: Status,
+}
+
 impl<A, H> Default for TransferMessage<A, H>
 where
     A: Default,
@@ -87,6 +186,7 @@ where
             amount: TokenBalance::default(),
             status: Status::Withdraw,
             action: Status::Withdraw,
+            phase: WithdrawPhase::Requested,
         }
     }
 }
@@ -121,6 +221,38 @@ where
     }
 }
 
+impl<A, H> Default for LimitMessage<A, H>
+where
+    A: Default,
+    H: Default,
+{
+    fn default() -> Self {
+        LimitMessage {
+            message_id: H::default(),
+            account: A::default(),
+            limit: u32::default(),
+            action: Status::SetMaxValidators,
+            status: Status::SetMaxValidators,
+        }
+    }
+}
+
+impl<A, H> Default for CancelMessage<A, H>
+where
+    A: Default,
+    H: Default,
+{
+    fn default() -> Self {
+        CancelMessage {
+            message_id: H::default(),
+            transfer_hash: H::default(),
+            account: A::default(),
+            action: Status::CancelMint,
+            status: Status::CancelMint,
+        }
+    }
+}
+
 impl<H> Default for BridgeTransfer<H>
 where
     H: Default,
@@ -131,7 +263,9 @@ where
             message_id: H::default(),
             open: true,
             votes: MemberId::default(),
+            signatures: MemberId::default(),
             kind: Kind::Transfer,
+            locked: TokenBalance::default(),
         }
     }
 }
\ No newline at end of file
@@ -144,7 +144,7 @@ fn testnet_genesis(
 			vesting: vec![],
 		}),
 		sudo: Some(SudoConfig {
-			key: root_key,
+			key: root_key.clone(),
 		}),
 		session: Some(SessionConfig {
 			validators: endowed_accounts.clone(),
@@ -200,7 +200,8 @@ fn testnet_genesis(
 		}),
 		bridge: Some(BridgeConfig {
 			validator_accounts: bridge_validators,
-			validators_count: 3u32,
+			super_admins: vec![],
+			operator: root_key,
 		}),
 	}
 }
@@ -338,7 +339,8 @@ fn akropolis_staging_genesis() -> GenesisConfig {
 		}),
 		bridge: Some(BridgeConfig {
 			validator_accounts: bridge_validators,
-			validators_count: 3u32, 
+			super_admins: vec![],
+			operator: endowed_accounts[0].clone(),
 		})
 	}
 }